@@ -0,0 +1,270 @@
+use crate::axis::{Axis, AxisKeyed};
+use crate::coord::Primitive;
+use crate::map::Map;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::unit::UnknownUnit;
+use num;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl Align {
+    /// Returns the offset from the start of a container that leaves `space` free in total.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Align;
+    /// assert_eq!(0, Align::Start.offset(10));
+    /// assert_eq!(5, Align::Center.offset(10));
+    /// assert_eq!(10, Align::End.offset(10));
+    /// ```
+    pub fn offset<T: num::Num>(self, space: T) -> T {
+        match self {
+            Align::Start => T::zero(),
+            Align::Center => space / (T::one() + T::one()),
+            Align::End => space,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Alignment {
+    horizontal: Align,
+    vertical: Align,
+}
+
+impl Alignment {
+    /// # Examples
+    /// ```
+    /// # use coord::Align;
+    /// # use coord::Alignment;
+    /// let alignment = Alignment::new(Align::End, Align::Start);
+    /// assert_eq!((Align::End, Align::Start), (alignment.horizontal(), alignment.vertical()));
+    /// ```
+    pub fn new(horizontal: Align, vertical: Align) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Align;
+    /// # use coord::Alignment;
+    /// assert_eq!(Alignment::new(Align::Center, Align::Center), Alignment::center());
+    /// ```
+    pub fn center() -> Self {
+        Self::new(Align::Center, Align::Center)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Align;
+    /// # use coord::Alignment;
+    /// assert_eq!(Align::End, Alignment::new(Align::End, Align::Start).horizontal());
+    /// ```
+    pub fn horizontal(self) -> Align {
+        self.horizontal
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Align;
+    /// # use coord::Alignment;
+    /// assert_eq!(Align::Start, Alignment::new(Align::End, Align::Start).vertical());
+    /// ```
+    pub fn vertical(self) -> Align {
+        self.vertical
+    }
+}
+
+fn align_span<T: num::Num + PartialOrd>(
+    align: Align,
+    container_origin: T,
+    container_size: T,
+    size: T,
+) -> T {
+    let space = if size < container_size {
+        container_size - size
+    } else {
+        T::zero()
+    };
+    container_origin + align.offset(space)
+}
+
+impl<T: Primitive + num::Num + PartialOrd, U> Rect<T, U> {
+    /// Content larger than the container along an axis is placed at the start of the container
+    /// on that axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Align;
+    /// # use coord::Alignment;
+    /// let container = Rect::new(Point::new(10, 20), Size::new(100, 50));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(90, 40), Size::new(20, 10)),
+    ///     Rect::new(Point::new(0, 0), Size::new(20, 10))
+    ///         .align_within(container, Alignment::new(Align::End, Align::Center)));
+    /// let container = Rect::new(Point::new(10u32, 20), Size::new(100, 50));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(10, 40), Size::new(120, 10)),
+    ///     Rect::new(Point::new(0, 0), Size::new(120, 10)).align_within(container, Alignment::center()));
+    /// ```
+    pub fn align_within(self, container: Rect<T, U>, alignment: Alignment) -> Self {
+        let (size, origin, container_size) = (self.size(), container.origin(), container.size());
+        Self::typed(
+            Point::typed(
+                align_span(
+                    alignment.horizontal(),
+                    origin.x(),
+                    container_size.width(),
+                    size.width(),
+                ),
+                align_span(
+                    alignment.vertical(),
+                    origin.y(),
+                    container_size.height(),
+                    size.height(),
+                ),
+            ),
+            size,
+        )
+    }
+}
+
+impl<T: Primitive + num::Float, U> Size<T, U> {
+    /// Returns the largest factor that scales this size to fit within `container`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// assert_eq!(2.0, Size::new(320.0, 180.0).scale_to_fit(Size::new(800.0, 360.0)));
+    /// ```
+    pub fn scale_to_fit(self, container: Size<T, U>) -> T {
+        let scale: Size<T, U> = (container, self).map(|(c, s)| c / s);
+        scale.width().min(scale.height())
+    }
+
+    /// Returns the smallest factor that scales this size to cover `container`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// assert_eq!(2.5, Size::new(320.0, 180.0).scale_to_fill(Size::new(800.0, 360.0)));
+    /// ```
+    pub fn scale_to_fill(self, container: Size<T, U>) -> T {
+        let scale: Size<T, U> = (container, self).map(|(c, s)| c / s);
+        scale.width().max(scale.height())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(640.0, 360.0), Size::new(320.0, 180.0).fit_within(Size::new(800.0, 360.0)));
+    /// ```
+    pub fn fit_within(self, container: Size<T, U>) -> Self {
+        let scale = self.scale_to_fit(container);
+        self.map(|n| n * scale)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(800.0, 450.0), Size::new(320.0, 180.0).fill(Size::new(800.0, 360.0)));
+    /// ```
+    pub fn fill(self, container: Size<T, U>) -> Self {
+        let scale = self.scale_to_fill(container);
+        self.map(|n| n * scale)
+    }
+
+    /// Fits this size centered within `container` and returns the content rect along with the
+    /// two bars covering the rest of the container.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// let letterbox = Size::new(320.0, 180.0).letterbox(Rect::new(Point::new(0.0, 0.0), Size::new(800.0, 360.0)));
+    /// assert_eq!(Rect::new(Point::new(80.0, 0.0), Size::new(640.0, 360.0)), letterbox.content());
+    /// assert_eq!(
+    ///     [
+    ///         Rect::new(Point::new(0.0, 0.0), Size::new(80.0, 360.0)),
+    ///         Rect::new(Point::new(720.0, 0.0), Size::new(80.0, 360.0)),
+    ///     ],
+    ///     letterbox.bars());
+    /// ```
+    pub fn letterbox(self, container: Rect<T, U>) -> Letterbox<T, U> {
+        let content = Rect::typed(container.origin(), self.fit_within(container.size()))
+            .align_within(container, Alignment::center());
+        let axis = if content.size().width() < container.size().width() {
+            Axis::X
+        } else {
+            Axis::Y
+        };
+        let (container_origin, container_size) = container.get(axis);
+        let (content_origin, content_size) = content.get(axis);
+        let content_end = content_origin + content_size;
+        Letterbox {
+            content,
+            bars: [
+                container.set(axis, (container_origin, content_origin - container_origin)),
+                container.set(
+                    axis,
+                    (content_end, container_origin + container_size - content_end),
+                ),
+            ],
+        }
+    }
+}
+
+pub struct Letterbox<T: Primitive, U = UnknownUnit> {
+    content: Rect<T, U>,
+    bars: [Rect<T, U>; 2],
+}
+
+impl_unit_traits!(Letterbox { content, bars });
+
+impl<T: Primitive, U> Letterbox<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// let letterbox = Size::new(4.0, 3.0).letterbox(Rect::new(Point::new(0.0, 0.0), Size::new(8.0, 8.0)));
+    /// assert_eq!(Rect::new(Point::new(0.0, 1.0), Size::new(8.0, 6.0)), letterbox.content());
+    /// ```
+    pub fn content(&self) -> Rect<T, U> {
+        self.content
+    }
+
+    /// Returns the bars before and after the content, which are empty when the aspect ratios match.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// let letterbox = Size::new(4.0, 3.0).letterbox(Rect::new(Point::new(0.0, 0.0), Size::new(8.0, 8.0)));
+    /// assert_eq!(
+    ///     [
+    ///         Rect::new(Point::new(0.0, 0.0), Size::new(8.0, 1.0)),
+    ///         Rect::new(Point::new(0.0, 7.0), Size::new(8.0, 1.0)),
+    ///     ],
+    ///     letterbox.bars());
+    /// ```
+    pub fn bars(&self) -> [Rect<T, U>; 2] {
+        self.bars
+    }
+}
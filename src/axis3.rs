@@ -0,0 +1,103 @@
+use crate::coord3::Coord3;
+use std::ops;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+pub trait AxisKeyed3: Coord3 {
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// # use coord::Axis3;
+    /// # use coord::AxisKeyed3;
+    /// assert_eq!(30, Vector3::new(30, 50, 70).get(Axis3::X));
+    /// assert_eq!(50, Vector3::new(30, 50, 70).get(Axis3::Y));
+    /// assert_eq!(70, Vector3::new(30, 50, 70).get(Axis3::Z));
+    /// ```
+    fn get(&self, axis: Axis3) -> Self::Item {
+        match axis {
+            Axis3::X => self.x(),
+            Axis3::Y => self.y(),
+            Axis3::Z => self.z(),
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Axis3;
+    /// # use coord::AxisKeyed3;
+    /// assert_eq!(Point3::new(10, 8, 3), Point3::new(5, 8, 3).set(Axis3::X, 10));
+    /// assert_eq!(Point3::new(5, 10, 3), Point3::new(5, 8, 3).set(Axis3::Y, 10));
+    /// assert_eq!(Point3::new(5, 8, 10), Point3::new(5, 8, 3).set(Axis3::Z, 10));
+    /// ```
+    fn set(&self, axis: Axis3, value: Self::Item) -> Self {
+        match axis {
+            Axis3::X => Self::from_x_y_z(value, self.y(), self.z()),
+            Axis3::Y => Self::from_x_y_z(self.x(), value, self.z()),
+            Axis3::Z => Self::from_x_y_z(self.x(), self.y(), value),
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Axis3;
+    /// # use coord::AxisKeyed3;
+    /// assert_eq!(Point3::new(10, 20, 32), Point3::new(10, 20, 30).add(Axis3::Z, 2));
+    /// ```
+    fn add<T>(&self, axis: Axis3, value: T) -> Self
+    where
+        Self::Item: ops::Add<T, Output = Self::Item>,
+    {
+        self.set(axis, self.get(axis) + value)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Axis3;
+    /// # use coord::AxisKeyed3;
+    /// assert_eq!(Point3::new(10, 20, 27), Point3::new(10, 20, 30).sub(Axis3::Z, 3));
+    /// ```
+    fn sub<T>(&self, axis: Axis3, value: T) -> Self
+    where
+        Self::Item: ops::Sub<T, Output = Self::Item>,
+    {
+        self.set(axis, self.get(axis) - value)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Axis3;
+    /// # use coord::AxisKeyed3;
+    /// assert_eq!(Point3::new(4, 5, 24), Point3::new(4, 5, 6).mul(Axis3::Z, 4));
+    /// ```
+    fn mul<T>(&self, axis: Axis3, value: T) -> Self
+    where
+        Self::Item: ops::Mul<T, Output = Self::Item>,
+    {
+        self.set(axis, self.get(axis) * value)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Axis3;
+    /// # use coord::AxisKeyed3;
+    /// assert_eq!(Point3::new(12, 20, 6), Point3::new(12, 20, 24).div(Axis3::Z, 4));
+    /// ```
+    fn div<T>(&self, axis: Axis3, value: T) -> Self
+    where
+        Self::Item: ops::Div<T, Output = Self::Item>,
+    {
+        self.set(axis, self.get(axis) / value)
+    }
+}
+
+impl<T: Coord3> AxisKeyed3 for T {}
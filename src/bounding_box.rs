@@ -0,0 +1,245 @@
+use crate::component_wise::ComponentWise;
+use crate::coord::Primitive;
+use crate::map::Map;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::unit::UnknownUnit;
+use num;
+use std::iter::FromIterator;
+use std::ops::{Add, Sub};
+
+pub struct BoundingBox<T: Primitive, U = UnknownUnit> {
+    bounds: Option<(Point<T, U>, Point<T, U>)>,
+}
+
+impl_unit_traits!(BoundingBox { bounds });
+
+impl<T: Primitive, U> BoundingBox<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// assert!(BoundingBox::<i32>::new().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { bounds: None }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// let mut bounds = BoundingBox::new();
+    /// assert!(bounds.is_empty());
+    /// bounds.include_point(Point::new(3, 4));
+    /// assert!(!bounds.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_none()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// let bounds: BoundingBox<_> = vec![Point::new(5, 2), Point::new(1, 8)].into_iter().collect();
+    /// assert_eq!(Some(Point::new(1, 2)), bounds.min());
+    /// ```
+    pub fn min(&self) -> Option<Point<T, U>> {
+        self.bounds.map(|(min, _)| min)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// let bounds: BoundingBox<_> = vec![Point::new(5, 2), Point::new(1, 8)].into_iter().collect();
+    /// assert_eq!(Some(Point::new(5, 8)), bounds.max());
+    /// ```
+    pub fn max(&self) -> Option<Point<T, U>> {
+        self.bounds.map(|(_, max)| max)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let mut bounds = BoundingBox::new();
+    /// bounds.include_point(Point::new(3, 4));
+    /// bounds.include_point(Point::new(10, 2));
+    /// assert_eq!(Some(Rect::new(Point::new(3, 2), Size::new(7, 2))), bounds.rect());
+    /// ```
+    pub fn include_point(&mut self, point: Point<T, U>)
+    where
+        T: PartialOrd,
+    {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.component_min(point), max.component_max(point)),
+            None => (point, point),
+        });
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let mut bounds = BoundingBox::new();
+    /// bounds.include_rect(Rect::new(Point::new(3, 4), Size::new(1, 1)));
+    /// bounds.include_rect(Rect::new(Point::new(10, 2), Size::new(1, 1)));
+    /// assert_eq!(Some(Rect::new(Point::new(3, 2), Size::new(8, 3))), bounds.rect());
+    /// ```
+    pub fn include_rect(&mut self, rect: Rect<T, U>)
+    where
+        T: PartialOrd,
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        self.include_point(rect.min());
+        self.include_point(rect.max());
+    }
+
+    /// Returns the rect spanning from the minimum to the maximum of the included points,
+    /// or `None` if nothing has been included.
+    ///
+    /// Points lying on the maximum edges are not `contains`-ed by the resulting rect; use
+    /// `cell_rect` when every point has to be covered.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// assert_eq!(None, BoundingBox::<i32>::new().rect());
+    /// ```
+    pub fn rect(&self) -> Option<Rect<T, U>>
+    where
+        T: PartialOrd + Sub<T, Output = T>,
+    {
+        self.bounds.map(|(min, max)| Rect::from_corners(min, max))
+    }
+
+    /// Returns the smallest rect containing the cells at all the included points, or `None` if
+    /// nothing has been included.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let bounds: BoundingBox<_> = vec![Point::new(5, 2), Point::new(1, 8)].into_iter().collect();
+    /// assert_eq!(Some(Rect::new(Point::new(1, 2), Size::new(5, 7))), bounds.cell_rect());
+    /// assert!(bounds.cell_rect().unwrap().contains(Point::new(5, 8)));
+    /// ```
+    pub fn cell_rect(&self) -> Option<Rect<T, U>>
+    where
+        T: PartialOrd + Sub<T, Output = T> + Add<T, Output = T> + num::One,
+    {
+        self.bounds
+            .map(|(min, max)| Rect::from_corners(min, max.map(|n| n + T::one())))
+    }
+}
+
+impl<T: Primitive, U> Default for BoundingBox<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Primitive + PartialOrd, U> Extend<Point<T, U>> for BoundingBox<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let mut bounds = BoundingBox::new();
+    /// bounds.extend(vec![Point::new(1, 7), Point::new(4, 3)]);
+    /// assert_eq!(Some(Rect::new(Point::new(1, 3), Size::new(3, 4))), bounds.rect());
+    /// ```
+    fn extend<I: IntoIterator<Item = Point<T, U>>>(&mut self, points: I) {
+        for point in points {
+            self.include_point(point);
+        }
+    }
+}
+
+impl<T: Primitive + PartialOrd, U> Extend<Rect<T, U>> for BoundingBox<T, U>
+where
+    Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let mut bounds = BoundingBox::new();
+    /// bounds.extend(vec![
+    ///     Rect::new(Point::new(1, 7), Size::new(2, 2)),
+    ///     Rect::new(Point::new(4, 3), Size::new(5, 1)),
+    /// ]);
+    /// assert_eq!(Some(Rect::new(Point::new(1, 3), Size::new(8, 6))), bounds.rect());
+    /// ```
+    fn extend<I: IntoIterator<Item = Rect<T, U>>>(&mut self, rects: I) {
+        for rect in rects {
+            self.include_rect(rect);
+        }
+    }
+}
+
+impl<T: Primitive + PartialOrd, U> FromIterator<Point<T, U>> for BoundingBox<T, U> {
+    fn from_iter<I: IntoIterator<Item = Point<T, U>>>(points: I) -> Self {
+        let mut bounds = Self::new();
+        bounds.extend(points);
+        bounds
+    }
+}
+
+impl<T: Primitive + PartialOrd, U> FromIterator<Rect<T, U>> for BoundingBox<T, U>
+where
+    Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::BoundingBox;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let bounds: BoundingBox<_> = Rect::new(Point::new(2, 3), Size::new(2, 2))
+    ///     .points()
+    ///     .map(|p| Rect::new(p, Size::new(1, 1)))
+    ///     .collect();
+    /// assert_eq!(Some(Rect::new(Point::new(2, 3), Size::new(2, 2))), bounds.rect());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Rect<T, U>>>(rects: I) -> Self {
+        let mut bounds = Self::new();
+        bounds.extend(rects);
+        bounds
+    }
+}
+
+impl<T: Primitive + PartialOrd + Sub<T, Output = T>, U> FromIterator<Point<T, U>>
+    for Option<Rect<T, U>>
+{
+    /// Collects the rect spanning from the minimum to the maximum of the points, or `None` if
+    /// there are no points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Some(Rect::new(Point::new(1, 2), Size::new(9, 6))),
+    ///     vec![Point::new(5, 8), Point::new(1, 4), Point::new(10, 2)].into_iter().collect());
+    /// assert_eq!(None, Vec::<Point<i32>>::new().into_iter().collect::<Option<Rect<_>>>());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Point<T, U>>>(points: I) -> Self {
+        points.into_iter().collect::<BoundingBox<T, U>>().rect()
+    }
+}
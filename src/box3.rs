@@ -0,0 +1,305 @@
+use crate::coord::Primitive;
+use crate::coord3::Coord3;
+use crate::map3::Map3;
+use crate::point::Point;
+use crate::point3::Point3;
+use crate::point_range_iterator::PointStep;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::size3::Size3;
+use crate::unit::UnknownUnit;
+use num;
+use std::fmt;
+use std::iter;
+use std::ops::{Add, Sub};
+
+pub struct Box3<T: Primitive, U = UnknownUnit> {
+    origin: Point3<T, U>,
+    size: Size3<T, U>,
+}
+
+impl_unit_traits!(Box3 { origin, size });
+
+impl<T: Primitive> Box3<T> {
+    pub fn new(origin: Point3<T>, size: Size3<T>) -> Self {
+        Self::typed(origin, size)
+    }
+}
+
+impl<T: Primitive, U> Box3<T, U> {
+    pub fn typed(origin: Point3<T, U>, size: Size3<T, U>) -> Self {
+        Self { origin, size }
+    }
+
+    /// Returns the box spanning from the smaller to the larger coordinates of `a` and `b` on
+    /// each axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(
+    ///     Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)),
+    ///     Box3::from_corners(Point3::new(5, 2, 9), Point3::new(1, 7, 3)));
+    /// ```
+    pub fn from_corners(a: Point3<T, U>, b: Point3<T, U>) -> Self
+    where
+        T: PartialOrd + Sub<T, Output = T>,
+    {
+        let min = |n: T, m: T| if m < n { m } else { n };
+        let max = |n: T, m: T| if n < m { m } else { n };
+        let (min, max): (Point3<T, U>, Point3<T, U>) = (
+            (a, b).map(|(n, m)| min(n, m)),
+            (a, b).map(|(n, m)| max(n, m)),
+        );
+        Self::typed(min, (max, min).map(|(n, m)| n - m))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(Point3::new(1, 2, 3), Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)).origin());
+    /// ```
+    pub fn origin(self) -> Point3<T, U> {
+        self.origin
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(Size3::new(4, 5, 6), Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)).size());
+    /// ```
+    pub fn size(self) -> Size3<T, U> {
+        self.size
+    }
+
+    pub fn min(self) -> Point3<T, U> {
+        self.origin()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(Point3::new(5, 7, 9), Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)).max());
+    /// ```
+    pub fn max(self) -> Point3<T, U>
+    where
+        T: Add<T, Output = T>,
+    {
+        self.origin() + self.size()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(Point3::new(3, 4, 6), Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)).center());
+    /// ```
+    pub fn center(self) -> Point3<T, U>
+    where
+        T: num::Num,
+    {
+        let two = T::one() + T::one();
+        (self.origin(), self.size()).map(|(o, s)| o + s / two)
+    }
+
+    /// Returns the horizontal cross-section of the box as a `Rect`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(1, 2), Size::new(4, 5)),
+    ///     Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)).rect());
+    /// ```
+    pub fn rect(self) -> Rect<T, U> {
+        Rect::typed(
+            Point::typed(self.origin().x(), self.origin().y()),
+            Size::typed(self.size().width(), self.size().height()),
+        )
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// let b = Box3::new(Point3::new(5, 10, 0), Size3::new(4, 8, 2));
+    /// assert!(b.contains(Point3::new(7, 13, 1)));
+    /// assert!(!b.contains(Point3::new(7, 13, 2)));
+    /// ```
+    pub fn contains(&self, point: Point3<T, U>) -> bool
+    where
+        T: PartialOrd + Add<T, Output = T>,
+    {
+        let (min, max) = (self.min(), self.max());
+        min.x() <= point.x()
+            && min.y() <= point.y()
+            && min.z() <= point.z()
+            && point.x() < max.x()
+            && point.y() < max.y()
+            && point.z() < max.z()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// let b = Box3::new(Point3::new(0, 0, 0), Size3::new(10, 10, 10));
+    /// assert!(b.contains_box(Box3::new(Point3::new(2, 3, 4), Size3::new(8, 7, 6))));
+    /// assert!(!b.contains_box(Box3::new(Point3::new(2, 3, 4), Size3::new(8, 7, 7))));
+    /// ```
+    pub fn contains_box(&self, other: Box3<T, U>) -> bool
+    where
+        T: PartialOrd + Add<T, Output = T>,
+    {
+        let (min, max) = (self.min(), self.max());
+        let (other_min, other_max) = (other.min(), other.max());
+        min.x() <= other_min.x()
+            && min.y() <= other_min.y()
+            && min.z() <= other_min.z()
+            && other_max.x() <= max.x()
+            && other_max.y() <= max.y()
+            && other_max.z() <= max.z()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// let b = Box3::new(Point3::new(0, 0, 0), Size3::new(10, 10, 10));
+    /// assert!(b.intersects(Box3::new(Point3::new(9, 9, 9), Size3::new(5, 5, 5))));
+    /// assert!(!b.intersects(Box3::new(Point3::new(9, 9, 10), Size3::new(5, 5, 5))));
+    /// ```
+    pub fn intersects(&self, other: Box3<T, U>) -> bool
+    where
+        T: PartialOrd + Sub<T, Output = T> + Add<T, Output = T>,
+    {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the overlapping box, or `None` if the boxes do not overlap with a positive volume.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(
+    ///     Some(Box3::new(Point3::new(5, 6, 7), Size3::new(5, 4, 3))),
+    ///     Box3::new(Point3::new(0, 0, 0), Size3::new(10, 10, 10))
+    ///         .intersection(Box3::new(Point3::new(5, 6, 7), Size3::new(10, 10, 10))));
+    /// ```
+    pub fn intersection(&self, other: Box3<T, U>) -> Option<Self>
+    where
+        T: PartialOrd + Sub<T, Output = T> + Add<T, Output = T>,
+    {
+        let max = |n: T, m: T| if n < m { m } else { n };
+        let min = |n: T, m: T| if m < n { m } else { n };
+        let (self_max, other_max) = (self.max(), other.max());
+        let lower: Point3<T, U> = (self.min(), other.min()).map(|(n, m)| max(n, m));
+        let upper: Point3<T, U> = (self_max, other_max).map(|(n, m)| min(n, m));
+        if lower.x() < upper.x() && lower.y() < upper.y() && lower.z() < upper.z() {
+            Some(Self::typed(lower, (upper, lower).map(|(n, m)| n - m)))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates the integer points in the box, x first, then y, then z.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(
+    ///     vec![
+    ///         Point3::new(10, 20, 0), Point3::new(11, 20, 0),
+    ///         Point3::new(10, 21, 0), Point3::new(11, 21, 0),
+    ///         Point3::new(10, 20, 1), Point3::new(11, 20, 1),
+    ///         Point3::new(10, 21, 1), Point3::new(11, 21, 1),
+    ///     ],
+    ///     Box3::new(Point3::new(10, 20, 0), Size3::new(2, 2, 2)).points().collect::<Vec<_>>());
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = Point3<T, U>>
+    where
+        T: PointStep,
+    {
+        let rect = self.rect();
+        let end = self.max().z();
+        iter::successors(Some(self.origin().z()), |&z| Some(z + T::one()))
+            .take_while(move |&z| z < end)
+            .flat_map(move |z| rect.points().map(move |p| Point3::typed(p.x(), p.y(), z)))
+    }
+
+    pub fn cast_unit<V>(self) -> Box3<T, V> {
+        Box3::typed(self.origin().cast_unit(), self.size().cast_unit())
+    }
+
+    pub fn to_untyped(self) -> Box3<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(b: Box3<T>) -> Self {
+        b.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, U> Default for Box3<T, U> {
+    fn default() -> Self {
+        Self::typed(Point3::default(), Size3::default())
+    }
+}
+
+impl<T: Primitive, U> Coord3 for Box3<T, U> {
+    type Item = (T, T);
+    type Unit = U;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Box3;
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// # use coord::Coord3;
+    /// assert_eq!(
+    ///     Box3::new(Point3::new(1, 2, 3), Size3::new(4, 5, 6)),
+    ///     Box3::from_x_y_z((1, 4), (2, 5), (3, 6)));
+    /// ```
+    fn from_x_y_z((x, width): (T, T), (y, height): (T, T), (z, depth): (T, T)) -> Self {
+        Self::typed(Point3::typed(x, y, z), Size3::typed(width, height, depth))
+    }
+
+    fn x(self) -> (T, T) {
+        (self.origin().x(), self.size().width())
+    }
+
+    fn y(self) -> (T, T) {
+        (self.origin().y(), self.size().height())
+    }
+
+    fn z(self) -> (T, T) {
+        (self.origin().z(), self.size().depth())
+    }
+}
+
+impl<T: Primitive + fmt::Display, U> fmt::Display for Box3<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.origin(), self.size())
+    }
+}
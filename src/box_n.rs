@@ -0,0 +1,134 @@
+use crate::coord::Primitive;
+use crate::coord_n::MapN;
+use crate::point_n::PointN;
+use crate::point_n_range_iterator::PointNRangeIterator;
+use crate::point_range_iterator::PointStep;
+use crate::size_n::SizeN;
+use crate::unit::UnknownUnit;
+use crate::vector_n::VectorN;
+use num;
+use std::array;
+use std::ops::{Add, Sub};
+
+pub struct BoxN<T: Primitive, const N: usize, U = UnknownUnit> {
+    origin: PointN<T, N, U>,
+    size: SizeN<T, N, U>,
+}
+
+impl_unit_traits!(BoxN<const N, U> { origin, size });
+
+impl<T: Primitive, const N: usize> BoxN<T, N> {
+    pub fn new(origin: PointN<T, N>, size: SizeN<T, N>) -> Self {
+        Self::typed(origin, size)
+    }
+}
+
+impl<T: Primitive, const N: usize, U> BoxN<T, N, U> {
+    pub fn typed(origin: PointN<T, N, U>, size: SizeN<T, N, U>) -> Self {
+        Self { origin, size }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoxN;
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// assert_eq!(
+    ///     BoxN::new(PointN::new([1, 2, 3, 4]), SizeN::new([4, 5, 6, 7])),
+    ///     BoxN::from_corners(PointN::new([5, 2, 9, 4]), PointN::new([1, 7, 3, 11])));
+    /// ```
+    pub fn from_corners(a: PointN<T, N, U>, b: PointN<T, N, U>) -> Self
+    where
+        T: PartialOrd + Sub<T, Output = T>,
+    {
+        let min: PointN<T, N, U> = (a, b).map(|(n, m)| if m < n { m } else { n });
+        let max: PointN<T, N, U> = (a, b).map(|(n, m)| if n < m { m } else { n });
+        Self::typed(min, (max, min).map(|(n, m)| n - m))
+    }
+
+    pub fn origin(self) -> PointN<T, N, U> {
+        self.origin
+    }
+
+    pub fn size(self) -> SizeN<T, N, U> {
+        self.size
+    }
+
+    pub fn min(self) -> PointN<T, N, U> {
+        self.origin()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoxN;
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// assert_eq!(
+    ///     PointN::new([5, 7, 9, 11]),
+    ///     BoxN::new(PointN::new([1, 2, 3, 4]), SizeN::new([4, 5, 6, 7])).max());
+    /// ```
+    pub fn max(self) -> PointN<T, N, U>
+    where
+        T: Add<T, Output = T>,
+    {
+        self.origin() + self.size()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoxN;
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// let b = BoxN::new(PointN::new([0, 0, 0, 0]), SizeN::new([2, 2, 2, 2]));
+    /// assert!(b.contains(PointN::new([1, 0, 1, 1])));
+    /// assert!(!b.contains(PointN::new([1, 0, 1, 2])));
+    /// ```
+    pub fn contains(&self, point: PointN<T, N, U>) -> bool
+    where
+        T: PartialOrd + Add<T, Output = T>,
+    {
+        let (min, max) = (self.min(), self.max());
+        (0..N).all(|i| min[i] <= point[i] && point[i] < max[i])
+    }
+
+    /// Iterates the points in the box by `step`, running through the first component fastest.
+    pub fn points_step<S: Primitive>(
+        &self,
+        step: VectorN<S, N, U>,
+    ) -> PointNRangeIterator<T, S, N, U>
+    where
+        T: PointStep<S> + Add<T, Output = T>,
+    {
+        PointNRangeIterator::new(self.origin(), self.max(), step)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoxN;
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// let b = BoxN::new(PointN::new([0, 0, 0, 0]), SizeN::new([2, 3, 1, 2]));
+    /// assert_eq!(12, b.points().count());
+    /// assert!(b.points().all(|p| b.contains(p)));
+    /// ```
+    pub fn points(&self) -> PointNRangeIterator<T, T, N, U>
+    where
+        T: PointStep + Add<T, Output = T>,
+    {
+        self.points_step(VectorN::typed(array::from_fn(|_| T::one())))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BoxN;
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// assert_eq!(60, BoxN::new(PointN::new([7, 8, 9]), SizeN::new([3, 4, 5])).volume());
+    /// ```
+    pub fn volume(self) -> T
+    where
+        T: num::One,
+    {
+        self.size().volume()
+    }
+}
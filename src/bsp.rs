@@ -0,0 +1,375 @@
+use crate::axis::{Axis, AxisKeyed};
+use crate::coord::Primitive;
+use crate::rect::Rect;
+use crate::size::Size;
+use num;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BspNode<T: Primitive> {
+    rect: Rect<T>,
+    depth: usize,
+    parent: Option<usize>,
+    children: Option<(usize, usize)>,
+}
+
+impl<T: Primitive> BspNode<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(Rect::new(Point::new(5, 0), Size::new(5, 10)), bsp.node(2).rect());
+    /// ```
+    pub fn rect(&self) -> Rect<T> {
+        self.rect
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(1, bsp.node(2).depth());
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(Some(bsp.root()), bsp.node(2).parent());
+    /// ```
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(Some((1, 2)), bsp.node(bsp.root()).children());
+    /// ```
+    pub fn children(&self) -> Option<(usize, usize)> {
+        self.children
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert!(!bsp.node(bsp.root()).is_leaf());
+    /// assert!(bsp.node(1).is_leaf());
+    /// ```
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_none()
+    }
+}
+
+/// A binary space partitioning tree whose nodes are identified by their indices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bsp<T: Primitive> {
+    nodes: Vec<BspNode<T>>,
+}
+
+impl<T: Primitive> Bsp<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// let bsp = BspSplitter::new(Size::new(2, 2)).build(rect, |_, _| None);
+    /// assert_eq!(rect, bsp.node(bsp.root()).rect());
+    /// ```
+    pub fn root(&self) -> usize {
+        0
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(Rect::new(Point::new(0, 0), Size::new(5, 10)), bsp.node(1).rect());
+    /// ```
+    pub fn node(&self, id: usize) -> &BspNode<T> {
+        &self.nodes[id]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(3, bsp.nodes().len());
+    /// ```
+    pub fn nodes(&self) -> &[BspNode<T>] {
+        &self.nodes
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let rect = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// let bsp = BspSplitter::new(Size::new(2, 2)).max_depth(1).build(rect, |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(None, bsp.sibling(bsp.root()));
+    /// assert_eq!(Some(2), bsp.sibling(1));
+    /// assert_eq!(Some(1), bsp.sibling(2));
+    /// ```
+    pub fn sibling(&self, id: usize) -> Option<usize> {
+        let (first, second) = self.nodes[self.nodes[id].parent?].children?;
+        Some(if first == id { second } else { first })
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(2, 2))
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::X, 0.5)));
+    /// assert_eq!(vec![1, 2], bsp.leaves().collect::<Vec<_>>());
+    /// ```
+    pub fn leaves(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.nodes.len()).filter(move |&id| self.nodes[id].is_leaf())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let rect = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// let bsp = BspSplitter::new(Size::new(2, 2)).max_depth(1).build(rect, |_, _| Some((Axis::X, 0.3)));
+    /// assert_eq!(
+    ///     vec![Rect::new(Point::new(0, 0), Size::new(3, 10)), Rect::new(Point::new(3, 0), Size::new(7, 10))],
+    ///     bsp.leaf_rects().collect::<Vec<_>>());
+    /// ```
+    pub fn leaf_rects(&self) -> impl Iterator<Item = Rect<T>> + '_ {
+        self.leaves().map(move |id| self.nodes[id].rect)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BspSplitter<T: Primitive> {
+    min_size: Size<T>,
+    min_ratio: f64,
+    max_ratio: f64,
+    max_depth: Option<usize>,
+}
+
+impl<T: Primitive + num::Num + num::NumCast + PartialOrd> BspSplitter<T> {
+    /// # Panics
+    /// Panics if `min_size` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let bsp = BspSplitter::new(Size::new(4, 4)).build_with_rng(Rect::new(Point::new(0, 0), Size::new(7, 7)), || 0.5);
+    /// assert_eq!(1, bsp.nodes().len());
+    /// ```
+    pub fn new(min_size: Size<T>) -> Self {
+        assert!(
+            T::zero() < min_size.width() && T::zero() < min_size.height(),
+            "min size must be positive"
+        );
+        Self {
+            min_size,
+            min_ratio: 0.0,
+            max_ratio: 1.0,
+            max_depth: None,
+        }
+    }
+
+    /// Restricts the position of each split to between `min` and `max` of the split rect.
+    ///
+    /// # Panics
+    /// Panics unless `0 <= min <= max <= 1`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(1, 1))
+    ///     .ratio(0.2, 0.4)
+    ///     .max_depth(1)
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(10, 10)), |_, _| Some((Axis::Y, 1.0)));
+    /// assert_eq!(Rect::new(Point::new(0, 0), Size::new(10, 4)), bsp.node(1).rect());
+    /// ```
+    pub fn ratio(self, min: f64, max: f64) -> Self {
+        assert!(
+            0.0 <= min && min <= max && max <= 1.0,
+            "ratio must satisfy 0 <= min <= max <= 1"
+        );
+        Self {
+            min_ratio: min,
+            max_ratio: max,
+            ..self
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let bsp = BspSplitter::new(Size::new(1, 1))
+    ///     .max_depth(2)
+    ///     .build_with_rng(Rect::new(Point::new(0, 0), Size::new(16, 16)), || 0.5);
+    /// assert_eq!(4, bsp.leaves().count());
+    /// ```
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    fn split(&self, rect: Rect<T>, axis: Axis, t: f64) -> Option<(Rect<T>, Rect<T>)> {
+        let (_, size) = rect.get(axis);
+        let min = self.min_size.get(axis);
+        if size < min + min {
+            return None;
+        }
+        let ratio = self.min_ratio + (self.max_ratio - self.min_ratio) * t;
+        let offset: T = num::NumCast::from(size.to_f64()? * ratio)?;
+        let offset = if offset < min {
+            min
+        } else if size - min < offset {
+            size - min
+        } else {
+            offset
+        };
+        Some(rect.split_at(axis, offset))
+    }
+
+    /// Splits `rect` recursively as long as `decide` returns an axis and a position in `0..=1`
+    /// within the ratio range, falling back to the other axis when the chosen one is too small.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let bsp = BspSplitter::new(Size::new(4, 4))
+    ///     .build(Rect::new(Point::new(0, 0), Size::new(16, 4)), |_, _| Some((Axis::Y, 0.5)));
+    /// assert_eq!(4, bsp.leaves().count());
+    /// ```
+    pub fn build(
+        &self,
+        rect: Rect<T>,
+        mut decide: impl FnMut(Rect<T>, usize) -> Option<(Axis, f64)>,
+    ) -> Bsp<T> {
+        let mut nodes = vec![BspNode {
+            rect,
+            depth: 0,
+            parent: None,
+            children: None,
+        }];
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(id) = queue.pop_front() {
+            let BspNode { rect, depth, .. } = nodes[id];
+            if self.max_depth.is_some_and(|max| max <= depth) {
+                continue;
+            }
+            let children = decide(rect, depth).and_then(|(axis, t)| {
+                self.split(rect, axis, t)
+                    .or_else(|| self.split(rect, axis.transpose(), t))
+            });
+            if let Some((first, second)) = children {
+                let first_id = nodes.len();
+                for child in [first, second].iter() {
+                    queue.push_back(nodes.len());
+                    nodes.push(BspNode {
+                        rect: *child,
+                        depth: depth + 1,
+                        parent: Some(id),
+                        children: None,
+                    });
+                }
+                nodes[id].children = Some((first_id, first_id + 1));
+            }
+        }
+        Bsp { nodes }
+    }
+
+    /// Splits `rect` as far as possible, choosing each split from `random` returning numbers in
+    /// `0..1`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::BspSplitter;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let bsp = BspSplitter::new(Size::new(2, 2)).build_with_rng(Rect::new(Point::new(0, 0), Size::new(4, 4)), || 0.5);
+    /// assert_eq!(4, bsp.leaves().count());
+    /// ```
+    pub fn build_with_rng(&self, rect: Rect<T>, mut random: impl FnMut() -> f64) -> Bsp<T> {
+        self.build(rect, |_, _| {
+            let axis = if random() < 0.5 { Axis::X } else { Axis::Y };
+            Some((axis, random()))
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use crate::coord::Primitive;
+use crate::map::Map;
+use crate::point::Point;
+use crate::point_range_iterator::PointStep;
+use crate::rect::Rect;
+use crate::size::Size;
+use num;
+
+fn div_euclid<T: num::Integer + Copy>(n: T, m: T) -> T {
+    let (q, r) = n.div_mod_floor(&m);
+    if r < T::zero() {
+        q + T::one()
+    } else {
+        q
+    }
+}
+
+pub(crate) fn rem_euclid<T: num::Integer + Copy>(n: T, m: T) -> T {
+    let r = n.mod_floor(&m);
+    if r < T::zero() {
+        r - m
+    } else {
+        r
+    }
+}
+
+impl<T: Primitive + num::Integer, U> Point<T, U> {
+    /// Divides each component by the corresponding component of `size` so that the remainder is
+    /// never negative, unlike `AxisKeyed::div` which truncates toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(1, -1), Point::new(5, -1).div_euclid(Size::new(4, 4)));
+    /// assert_eq!(Point::new(-2, -1), Point::new(-5, -4).div_euclid(Size::new(4, 4)));
+    /// assert_eq!(Point::new(2, 0), Point::new(-5, 3).div_euclid(Size::new(-3, 4)));
+    /// assert_eq!(Point::new(-715827883, 0), Point::new(i32::MIN, 0).div_euclid(Size::new(3, 1)));
+    /// ```
+    pub fn div_euclid(self, size: Size<T, U>) -> Self {
+        (self, size).map(|(n, m)| div_euclid(n, m))
+    }
+
+    /// Returns the least non-negative remainder of each component divided by the corresponding
+    /// component of `size`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(1, 3), Point::new(5, -1).rem_euclid(Size::new(4, 4)));
+    /// assert_eq!(Point::new(1, 3), Point::new(-5, 3).rem_euclid(Size::new(-3, 4)));
+    /// ```
+    pub fn rem_euclid(self, size: Size<T, U>) -> Self {
+        (self, size).map(|(n, m)| rem_euclid(n, m))
+    }
+
+    /// Splits the point into the coordinates of the chunk of `chunk_size` containing it and the
+    /// point local to the chunk.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!((Point::new(-1, 0), Point::new(15, 3)), Point::new(-1, 3).to_chunk(Size::new(16, 16)));
+    /// ```
+    pub fn to_chunk(self, chunk_size: Size<T, U>) -> (Self, Self) {
+        (self.div_euclid(chunk_size), self.rem_euclid(chunk_size))
+    }
+
+    /// Returns the point at `local` in the chunk of `chunk_size` at `chunk`, the inverse of
+    /// `to_chunk`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(-1, 3), Point::from_chunk(Point::new(-1, 0), Point::new(15, 3), Size::new(16, 16)));
+    /// ```
+    pub fn from_chunk(chunk: Self, local: Self, chunk_size: Size<T, U>) -> Self {
+        ((chunk, chunk_size), local).map(|((c, s), l)| c * s + l)
+    }
+}
+
+impl<T: Primitive + num::Integer, U> Rect<T, U> {
+    /// Iterates the coordinates of the chunks of `chunk_size` the rect overlaps in row-major
+    /// order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     vec![Point::new(-1, 0), Point::new(0, 0), Point::new(-1, 1), Point::new(0, 1)],
+    ///     Rect::new(Point::new(-2, 3), Size::new(6, 2)).chunks_overlapping(Size::new(4, 4)).collect::<Vec<_>>());
+    /// assert_eq!(0, Rect::new(Point::new(-2, 3), Size::new(0, 2)).chunks_overlapping(Size::new(4, 4)).count());
+    /// ```
+    pub fn chunks_overlapping(self, chunk_size: Size<T, U>) -> impl Iterator<Item = Point<T, U>>
+    where
+        T: PointStep,
+    {
+        let min = self.min().div_euclid(chunk_size);
+        let max = if self.size().width().is_zero() || self.size().height().is_zero() {
+            min
+        } else {
+            let last: Point<T, U> = self.max().map(|n| n - T::one());
+            last.div_euclid(chunk_size).map(|n| n + T::one())
+        };
+        Rect::from_corners(min, max).points()
+    }
+}
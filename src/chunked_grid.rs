@@ -0,0 +1,299 @@
+use crate::bounding_box::BoundingBox;
+use crate::grid::Grid;
+use crate::map::Map;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use std::collections::HashMap;
+use std::ops::Index;
+
+/// An unbounded grid allocating dense chunks of `chunk_size` cells on demand, filled with a
+/// default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedGrid<T> {
+    chunk_size: Size<i32>,
+    default: T,
+    chunks: HashMap<Point<i32>, Grid<T>>,
+}
+
+impl<T> ChunkedGrid<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = ChunkedGrid::new(Size::new(16, 8), 0);
+    /// assert_eq!(Size::new(16, 8), grid.chunk_size());
+    /// ```
+    pub fn chunk_size(&self) -> Size<i32> {
+        self.chunk_size
+    }
+
+    /// Returns the chunk containing `point` and the point local to the chunk.
+    fn locate(&self, point: Point<i32>) -> (Point<i32>, Point<usize>) {
+        let (chunk, local) = point.to_chunk(self.chunk_size);
+        (chunk, local.map(|n| n as usize))
+    }
+
+    /// Returns the rect of the cells in `chunk`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = ChunkedGrid::new(Size::new(16, 8), 0);
+    /// assert_eq!(
+    ///     Rect::new(Point::new(-16, 8), Size::new(16, 8)),
+    ///     grid.chunk_rect(Point::new(-1, 1)));
+    /// ```
+    pub fn chunk_rect(&self, chunk: Point<i32>) -> Rect<i32> {
+        Rect::new(
+            Point::from_chunk(chunk, Point::new(0, 0), self.chunk_size),
+            self.chunk_size,
+        )
+    }
+
+    /// Returns the allocated chunk at the chunk coordinates `chunk`, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(2, 2), 0);
+    /// grid.insert(Point::new(-1, 2), 5);
+    /// assert_eq!(&[0, 5, 0, 0], grid.chunk(Point::new(-1, 1)).unwrap().cells());
+    /// assert_eq!(None, grid.chunk(Point::new(0, 0)));
+    /// ```
+    pub fn chunk(&self, chunk: Point<i32>) -> Option<&Grid<T>> {
+        self.chunks.get(&chunk)
+    }
+
+    /// Iterates the chunk coordinates of the allocated chunks in arbitrary order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(4, 4), 0);
+    /// grid.insert(Point::new(-1, 5), 1);
+    /// grid.insert(Point::new(-2, 6), 2);
+    /// assert_eq!(vec![Point::new(-1, 1)], grid.chunks().collect::<Vec<_>>());
+    /// ```
+    pub fn chunks(&self) -> impl Iterator<Item = Point<i32>> + '_ {
+        self.chunks.keys().copied()
+    }
+
+    /// Returns the cell at `point`, or `None` if its chunk has not been allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(16, 16), 0);
+    /// grid.insert(Point::new(-1, -1), 5);
+    /// assert_eq!(Some(&5), grid.get(Point::new(-1, -1)));
+    /// assert_eq!(Some(&0), grid.get(Point::new(-16, -16)));
+    /// assert_eq!(None, grid.get(Point::new(0, 0)));
+    /// ```
+    pub fn get(&self, point: Point<i32>) -> Option<&T> {
+        let (chunk, local) = self.locate(point);
+        self.chunks.get(&chunk)?.get(local)
+    }
+
+    /// Returns the cell at `point` mutably, or `None` if its chunk has not been allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(16, 16), 0);
+    /// assert_eq!(None, grid.get_mut(Point::new(3, 3)));
+    /// grid.insert(Point::new(0, 0), 1);
+    /// *grid.get_mut(Point::new(3, 3)).unwrap() = 7;
+    /// assert_eq!(7, grid[Point::new(3, 3)]);
+    /// ```
+    pub fn get_mut(&mut self, point: Point<i32>) -> Option<&mut T> {
+        let (chunk, local) = self.locate(point);
+        self.chunks.get_mut(&chunk)?.get_mut(local)
+    }
+
+    /// Iterates the cells of the allocated chunks with their points, chunk by chunk in
+    /// arbitrary order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(2, 2), 0);
+    /// grid.insert(Point::new(-1, -1), 5);
+    /// let mut cells = grid.iter().map(|(p, &n)| (p, n)).collect::<Vec<_>>();
+    /// cells.sort_by_key(|&(p, _)| (p.y(), p.x()));
+    /// assert_eq!(
+    ///     vec![
+    ///         (Point::new(-2, -2), 0),
+    ///         (Point::new(-1, -2), 0),
+    ///         (Point::new(-2, -1), 0),
+    ///         (Point::new(-1, -1), 5),
+    ///     ],
+    ///     cells);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i32>, &T)> {
+        let chunk_size = self.chunk_size;
+        self.chunks.iter().flat_map(move |(&chunk, grid)| {
+            grid.iter()
+                .map(move |(local, cell)| (to_global(chunk_size, chunk, local), cell))
+        })
+    }
+
+    /// Iterates the cells of the allocated chunks mutably with their points, chunk by chunk in
+    /// arbitrary order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(2, 2), 0);
+    /// grid.insert(Point::new(3, 0), 0);
+    /// for (p, cell) in grid.iter_mut() {
+    ///     *cell = p.x() + p.y();
+    /// }
+    /// assert_eq!(4, grid[Point::new(3, 1)]);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<i32>, &mut T)> {
+        let chunk_size = self.chunk_size;
+        self.chunks.iter_mut().flat_map(move |(&chunk, grid)| {
+            grid.iter_mut()
+                .map(move |(local, cell)| (to_global(chunk_size, chunk, local), cell))
+        })
+    }
+
+    /// Returns the smallest rect covering all the allocated chunks, or `None` if no chunk is
+    /// allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(8, 4), ' ');
+    /// assert_eq!(None, grid.bounds());
+    /// grid.insert(Point::new(-1, 0), 'a');
+    /// grid.insert(Point::new(9, 5), 'b');
+    /// assert_eq!(Some(Rect::new(Point::new(-8, 0), Size::new(24, 8))), grid.bounds());
+    /// ```
+    pub fn bounds(&self) -> Option<Rect<i32>> {
+        self.chunks
+            .keys()
+            .map(|&chunk| self.chunk_rect(chunk))
+            .collect::<BoundingBox<_, _>>()
+            .rect()
+    }
+}
+
+fn to_global(chunk_size: Size<i32>, chunk: Point<i32>, local: Point<usize>) -> Point<i32> {
+    Point::from_chunk(chunk, local.map(|n| n as i32), chunk_size)
+}
+
+impl<T: Clone> ChunkedGrid<T> {
+    /// # Panics
+    /// Panics if `chunk_size` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = ChunkedGrid::new(Size::new(16, 16), '.');
+    /// assert_eq!(None, grid.get(Point::new(0, 0)));
+    /// assert_eq!(None, grid.bounds());
+    /// ```
+    pub fn new(chunk_size: Size<i32>, default: T) -> Self {
+        assert!(
+            0 < chunk_size.width() && 0 < chunk_size.height(),
+            "chunk size must be positive"
+        );
+        Self {
+            chunk_size,
+            default,
+            chunks: HashMap::new(),
+        }
+    }
+
+    /// Returns the cell at `point`, allocating its chunk first if needed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(16, 16), 0);
+    /// *grid.get_or_insert_mut(Point::new(-1, 0)) += 3;
+    /// assert_eq!(Some(&3), grid.get(Point::new(-1, 0)));
+    /// assert_eq!(Some(&0), grid.get(Point::new(-16, 15)));
+    /// ```
+    pub fn get_or_insert_mut(&mut self, point: Point<i32>) -> &mut T {
+        let (chunk, local) = self.locate(point);
+        let (chunk_size, default) = (self.chunk_size, &self.default);
+        let grid = self.chunks.entry(chunk).or_insert_with(|| {
+            Grid::new(
+                Size::new(chunk_size.width() as usize, chunk_size.height() as usize),
+                default.clone(),
+            )
+        });
+        &mut grid[local]
+    }
+
+    /// Sets the cell at `point`, returning the previous value if its chunk was allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(16, 16), 0);
+    /// assert_eq!(None, grid.insert(Point::new(1, 2), 5));
+    /// assert_eq!(Some(5), grid.insert(Point::new(1, 2), 6));
+    /// assert_eq!(Some(0), grid.insert(Point::new(2, 1), 7));
+    /// ```
+    pub fn insert(&mut self, point: Point<i32>, value: T) -> Option<T> {
+        let (chunk, local) = self.locate(point);
+        if let Some(grid) = self.chunks.get_mut(&chunk) {
+            return Some(std::mem::replace(&mut grid[local], value));
+        }
+        *self.get_or_insert_mut(point) = value;
+        None
+    }
+}
+
+impl<T> Index<Point<i32>> for ChunkedGrid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if the chunk of `point` has not been allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::ChunkedGrid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = ChunkedGrid::new(Size::new(16, 16), 0);
+    /// grid.insert(Point::new(-1, -1), 5);
+    /// assert_eq!(5, grid[Point::new(-1, -1)]);
+    /// assert_eq!(0, grid[Point::new(-16, -16)]);
+    /// ```
+    fn index(&self, point: Point<i32>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("the chunk of {} is not allocated", point),
+        }
+    }
+}
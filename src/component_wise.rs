@@ -0,0 +1,123 @@
+use crate::coord::{Coord, Primitive};
+use crate::map::Map;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use num;
+use std::ops::Add;
+
+fn partial_min<T: PartialOrd>(n: T, m: T) -> T {
+    if m < n {
+        m
+    } else {
+        n
+    }
+}
+
+fn partial_max<T: PartialOrd>(n: T, m: T) -> T {
+    if n < m {
+        m
+    } else {
+        n
+    }
+}
+
+pub trait ComponentWise: Coord {
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::ComponentWise;
+    /// assert_eq!(Point::new(3, 2), Point::new(3, 8).component_min(Point::new(5, 2)));
+    /// ```
+    fn component_min(self, other: Self) -> Self
+    where
+        Self::Item: PartialOrd,
+    {
+        (self, other).map(|(n, m)| partial_min(n, m))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::ComponentWise;
+    /// assert_eq!(Point::new(5, 8), Point::new(3, 8).component_max(Point::new(5, 2)));
+    /// ```
+    fn component_max(self, other: Self) -> Self
+    where
+        Self::Item: PartialOrd,
+    {
+        (self, other).map(|(n, m)| partial_max(n, m))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector;
+    /// # use coord::ComponentWise;
+    /// assert_eq!(
+    ///     Vector::new(0, 10),
+    ///     Vector::new(-5, 15).clamp(Vector::new(0, 0), Vector::new(10, 10)));
+    /// assert_eq!(
+    ///     Vector::new(2.5, 4.0),
+    ///     Vector::new(2.5, 4.0).clamp(Vector::new(0.0, 0.0), Vector::new(10.0, 10.0)));
+    /// ```
+    fn clamp(self, lo: Self, hi: Self) -> Self
+    where
+        Self::Item: PartialOrd,
+    {
+        (self, lo, hi).map(|(n, lo, hi)| partial_min(partial_max(n, lo), hi))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector;
+    /// # use coord::ComponentWise;
+    /// assert_eq!(Vector::new(3, 4), Vector::new(-3, 4).abs());
+    /// ```
+    fn abs(self) -> Self
+    where
+        Self::Item: num::Signed,
+    {
+        self.map(|n| num::Signed::abs(&n))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector;
+    /// # use coord::ComponentWise;
+    /// assert_eq!(Vector::new(-1, 0), Vector::new(-3, 0).signum());
+    /// assert_eq!(Vector::new(1.0, -1.0), Vector::new(2.5, -0.5).signum());
+    /// ```
+    fn signum(self) -> Self
+    where
+        Self::Item: num::Signed,
+    {
+        self.map(|n| num::Signed::signum(&n))
+    }
+}
+
+impl<T: Coord> ComponentWise for T {}
+
+impl<T: Primitive + num::Integer, U> Point<T, U> {
+    /// Returns the point within `rect` closest to this point, or `None` if the rect is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(10, 20), Size::new(5, 5));
+    /// assert_eq!(Some(Point::new(10, 24)), Point::new(3, 100).clamp_to(rect));
+    /// assert_eq!(Some(Point::new(12, 21)), Point::new(12, 21).clamp_to(rect));
+    /// assert_eq!(None, Point::new(0u32, 0).clamp_to(Rect::new(Point::new(0, 0), Size::new(0, 5))));
+    /// ```
+    pub fn clamp_to(self, rect: Rect<T, U>) -> Option<Self>
+    where
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        if rect.size().width().is_zero() || rect.size().height().is_zero() {
+            return None;
+        }
+        let last = rect.max().map(|n: T| n - T::one());
+        Some(self.clamp(rect.origin(), last))
+    }
+}
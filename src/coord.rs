@@ -4,6 +4,7 @@ impl<T: Copy> Primitive for T {}
 
 pub trait Coord: Copy {
     type Item: Primitive;
+    type Unit;
 
     fn from_x_y(x: Self::Item, y: Self::Item) -> Self;
 
@@ -0,0 +1,14 @@
+use crate::coord::Primitive;
+
+pub trait Coord3: Copy {
+    type Item: Primitive;
+    type Unit;
+
+    fn from_x_y_z(x: Self::Item, y: Self::Item, z: Self::Item) -> Self;
+
+    fn x(self) -> Self::Item;
+
+    fn y(self) -> Self::Item;
+
+    fn z(self) -> Self::Item;
+}
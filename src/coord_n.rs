@@ -0,0 +1,56 @@
+use crate::coord::Primitive;
+use std::array;
+
+pub trait CoordN<const N: usize>: Copy {
+    type Item: Primitive;
+    type Unit;
+
+    fn from_array(components: [Self::Item; N]) -> Self;
+
+    fn to_array(self) -> [Self::Item; N];
+}
+
+pub trait MapN<const N: usize>: CoordN<N> {
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// # use coord::MapN;
+    /// assert_eq!(VectorN::new([8, 6, 4, 2]), VectorN::new([4, 3, 2, 1]).map(|n| n * 2));
+    /// ```
+    fn map<R: CoordN<N, Unit = Self::Unit>>(
+        &self,
+        f: impl FnMut(Self::Item) -> <R as CoordN<N>>::Item,
+    ) -> R {
+        R::from_array(self.to_array().map(f))
+    }
+}
+
+impl<C: CoordN<N>, const N: usize> MapN<N> for C {}
+
+impl<C0: CoordN<N>, C1: CoordN<N, Unit = <C0 as CoordN<N>>::Unit>, const N: usize> CoordN<N>
+    for (C0, C1)
+{
+    type Item = (<C0 as CoordN<N>>::Item, <C1 as CoordN<N>>::Item);
+    type Unit = <C0 as CoordN<N>>::Unit;
+
+    fn from_array(components: [Self::Item; N]) -> Self {
+        (
+            C0::from_array(components.map(|(n, _)| n)),
+            C1::from_array(components.map(|(_, m)| m)),
+        )
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::CoordN;
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// assert_eq!(
+    ///     [(1, 10), (2, 20), (3, 30)],
+    ///     (PointN::new([1, 2, 3]), VectorN::new([10, 20, 30])).to_array());
+    /// ```
+    fn to_array(self) -> [Self::Item; N] {
+        let (n, m) = (self.0.to_array(), self.1.to_array());
+        array::from_fn(|i| (n[i], m[i]))
+    }
+}
@@ -0,0 +1,300 @@
+use crate::angle::{Angle, AnglePrimitive};
+use crate::axis::Axis;
+use crate::coord::Primitive;
+use crate::vector::Vector;
+use num;
+
+/// The four directions on a y-down grid, where `North` is negative y.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// All the directions clockwise from `North`.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// assert_eq!(vec![Direction4::North, Direction4::East, Direction4::South, Direction4::West], Direction4::iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// assert_eq!(Direction4::East, Direction4::North.rotate_cw());
+    /// assert_eq!(Direction4::North, Direction4::West.rotate_cw());
+    /// ```
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// assert_eq!(Direction4::West, Direction4::North.rotate_ccw());
+    /// ```
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// assert_eq!(Direction4::South, Direction4::North.opposite());
+    /// assert_eq!(Direction4::West, Direction4::East.opposite());
+    /// ```
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// # use coord::Axis;
+    /// assert_eq!(Axis::Y, Direction4::North.axis());
+    /// assert_eq!(Axis::X, Direction4::West.axis());
+    /// ```
+    pub fn axis(self) -> Axis {
+        match self {
+            Direction4::North | Direction4::South => Axis::Y,
+            Direction4::East | Direction4::West => Axis::X,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// # use coord::Vector;
+    /// assert_eq!(Vector::new(0, -1), Direction4::North.to_vector());
+    /// assert_eq!(Vector::new(1.0, 0.0), Direction4::East.to_vector());
+    /// ```
+    pub fn to_vector<T: Primitive + num::Signed, U>(self) -> Vector<T, U> {
+        Direction8::from(self).to_vector()
+    }
+
+    /// Returns the direction of a vector along an axis by its sign, or `None` if the vector is
+    /// zero or diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// # use coord::Vector;
+    /// assert_eq!(Some(Direction4::South), Direction4::from_vector(Vector::new(0, 1)));
+    /// assert_eq!(Some(Direction4::West), Direction4::from_vector(Vector::new(-3, 0)));
+    /// assert_eq!(None, Direction4::from_vector(Vector::new(1, 1)));
+    /// assert_eq!(None, Direction4::from_vector(Vector::new(0, 0)));
+    /// ```
+    pub fn from_vector<T: Primitive + num::Signed, U>(vector: Vector<T, U>) -> Option<Self> {
+        Direction8::from_vector(vector)?.to_direction4()
+    }
+
+    /// Returns the angle from the x axis towards the y axis, so that `East` is zero and `South`
+    /// is a quarter turn.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// # use coord::Angle;
+    /// assert_eq!(Angle::<f64>::pi() / 2.0, Direction4::South.to_angle());
+    /// ```
+    pub fn to_angle<T: AnglePrimitive>(self) -> Angle<T> {
+        Direction8::from(self).to_angle()
+    }
+}
+
+/// The eight directions on a y-down grid, where `North` is negative y.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All the directions clockwise from `North`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// assert_eq!(8, Direction8::iter().count());
+    /// assert_eq!(Some(Direction8::NorthEast), Direction8::iter().nth(1));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Rotates the direction by 45 degrees clockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// assert_eq!(Direction8::NorthEast, Direction8::North.rotate_cw());
+    /// assert_eq!(Direction8::North, Direction8::NorthWest.rotate_cw());
+    /// ```
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotates the direction by 45 degrees counterclockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// assert_eq!(Direction8::NorthWest, Direction8::North.rotate_ccw());
+    /// ```
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// assert_eq!(Direction8::SouthWest, Direction8::NorthEast.opposite());
+    /// ```
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Returns the axis of the direction, or `None` if it is diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// # use coord::Axis;
+    /// assert_eq!(Some(Axis::X), Direction8::East.axis());
+    /// assert_eq!(None, Direction8::SouthEast.axis());
+    /// ```
+    pub fn axis(self) -> Option<Axis> {
+        Some(self.to_direction4()?.axis())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// # use coord::Direction4;
+    /// assert_eq!(Some(Direction4::West), Direction8::West.to_direction4());
+    /// assert_eq!(None, Direction8::NorthWest.to_direction4());
+    /// ```
+    pub fn to_direction4(self) -> Option<Direction4> {
+        if self.is_diagonal() {
+            None
+        } else {
+            Some(Direction4::ALL[self as usize / 2])
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// # use coord::Vector;
+    /// assert_eq!(Vector::new(1, -1), Direction8::NorthEast.to_vector());
+    /// assert_eq!(Vector::new(-1, 0), Direction8::West.to_vector());
+    /// ```
+    pub fn to_vector<T: Primitive + num::Signed, U>(self) -> Vector<T, U> {
+        let (zero, one) = (T::zero(), T::one());
+        let (x, y) = match self {
+            Direction8::North => (zero, -one),
+            Direction8::NorthEast => (one, -one),
+            Direction8::East => (one, zero),
+            Direction8::SouthEast => (one, one),
+            Direction8::South => (zero, one),
+            Direction8::SouthWest => (-one, one),
+            Direction8::West => (-one, zero),
+            Direction8::NorthWest => (-one, -one),
+        };
+        Vector::typed(x, y)
+    }
+
+    /// Returns the direction of a vector by the signs of its components, or `None` if the
+    /// vector is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// # use coord::Vector;
+    /// assert_eq!(Some(Direction8::SouthWest), Direction8::from_vector(Vector::new(-1, 1)));
+    /// assert_eq!(Some(Direction8::NorthEast), Direction8::from_vector(Vector::new(2.5, -0.5)));
+    /// assert_eq!(None, Direction8::from_vector(Vector::new(0, 0)));
+    /// ```
+    pub fn from_vector<T: Primitive + num::Signed, U>(vector: Vector<T, U>) -> Option<Self> {
+        let sign = |n: T| {
+            if n.is_zero() {
+                0
+            } else if n.is_positive() {
+                1
+            } else {
+                -1
+            }
+        };
+        Some(match (sign(vector.x()), sign(vector.y())) {
+            (0, -1) => Direction8::North,
+            (1, -1) => Direction8::NorthEast,
+            (1, 0) => Direction8::East,
+            (1, 1) => Direction8::SouthEast,
+            (0, 1) => Direction8::South,
+            (-1, 1) => Direction8::SouthWest,
+            (-1, 0) => Direction8::West,
+            (-1, -1) => Direction8::NorthWest,
+            _ => return None,
+        })
+    }
+
+    /// Returns the angle from the x axis towards the y axis in `[0, 2π)`, so that `East` is zero
+    /// and `South` is a quarter turn.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Direction8;
+    /// # use coord::Angle;
+    /// assert_eq!(Angle::new(0.0), Direction8::East.to_angle::<f64>());
+    /// assert_eq!(Angle::<f64>::pi() * 1.75, Direction8::NorthEast.to_angle());
+    /// ```
+    pub fn to_angle<T: AnglePrimitive>(self) -> Angle<T> {
+        let eighths = (self as usize + 6) % 8;
+        Angle::new(T::pi() * T::from(eighths).unwrap() / T::from(4).unwrap())
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    /// # Examples
+    /// ```
+    /// # use coord::Direction4;
+    /// # use coord::Direction8;
+    /// assert_eq!(Direction8::South, Direction8::from(Direction4::South));
+    /// ```
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
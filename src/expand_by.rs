@@ -2,14 +2,15 @@ use crate::coord::Primitive;
 use crate::map::Map;
 use crate::rect::Rect;
 use crate::size::Size;
+use crate::unit::UnknownUnit;
 use crate::vector::Vector;
 use std::ops::Add;
 
-pub trait ExpandBy<T: Primitive> {
-    fn expand_by(self, vector: Vector<T>) -> Self;
+pub trait ExpandBy<T: Primitive, U = UnknownUnit> {
+    fn expand_by(self, vector: Vector<T, U>) -> Self;
 }
 
-impl<VP: Primitive, T: Primitive + Add<VP, Output = T>> ExpandBy<VP> for Size<T> {
+impl<VP: Primitive, T: Primitive + Add<VP, Output = T>, U> ExpandBy<VP, U> for Size<T, U> {
     /// # Examples
     /// ```
     /// # use coord::Size;
@@ -17,14 +18,14 @@ impl<VP: Primitive, T: Primitive + Add<VP, Output = T>> ExpandBy<VP> for Size<T>
     /// # use coord::ExpandBy;
     /// assert_eq!(Size::new(140, 180), Size::new(100, 100).expand_by(Vector::new(40, 80)));
     /// ```
-    fn expand_by(self, vector: Vector<VP>) -> Self {
+    fn expand_by(self, vector: Vector<VP, U>) -> Self {
         (self, vector).map(|(s, v)| s + v)
     }
 }
 
-impl<T: Primitive, VP: Primitive> ExpandBy<VP> for Rect<T>
+impl<T: Primitive, VP: Primitive, U> ExpandBy<VP, U> for Rect<T, U>
 where
-    Size<T>: ExpandBy<VP>,
+    Size<T, U>: ExpandBy<VP, U>,
 {
     /// # Examples
     /// ```
@@ -35,7 +36,7 @@ where
     /// # use coord::ExpandBy;
     /// assert_eq!(Rect::new(Point::new(2, 3), Size::new(14, 15)), Rect::new(Point::new(2, 3), Size::new(4, 5)).expand_by(Vector::new(10, 10)));
     /// ```
-    fn expand_by(self, vector: Vector<VP>) -> Self {
-        Self::new(self.origin(), self.size().expand_by(vector))
+    fn expand_by(self, vector: Vector<VP, U>) -> Self {
+        Self::typed(self.origin(), self.size().expand_by(vector))
     }
 }
@@ -0,0 +1,342 @@
+use crate::axis::Axis;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::symmetry::Symmetry;
+use std::ops::{Index, IndexMut};
+
+/// A dense grid of cells stored in row-major order and indexed by `Point<usize>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    size: Size<usize>,
+    cells: Vec<T>,
+}
+
+fn cell_count(size: Size<usize>) -> Option<usize> {
+    size.width().checked_mul(size.height())
+}
+
+impl<T> Grid<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(3, 2), |p| p.x() * 10 + p.y());
+    /// assert_eq!(21, grid[Point::new(2, 1)]);
+    /// ```
+    pub fn from_fn(size: Size<usize>, mut f: impl FnMut(Point<usize>) -> T) -> Self {
+        let cells = Rect::new(Point::new(0, 0), size)
+            .points()
+            .map(&mut f)
+            .collect();
+        Self { size, cells }
+    }
+
+    /// Returns the grid of `cells` in row-major order, or `None` if the number of cells does not
+    /// match `size`, including when it overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_vec(Size::new(2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(3, grid[Point::new(0, 1)]);
+    /// assert_eq!(None, Grid::from_vec(Size::new(2, 2), vec![1, 2, 3]));
+    /// assert_eq!(None, Grid::from_vec(Size::new(usize::MAX, 2), vec![1, 2, 3]));
+    /// ```
+    pub fn from_vec(size: Size<usize>, cells: Vec<T>) -> Option<Self> {
+        if cell_count(size) == Some(cells.len()) {
+            Some(Self { size, cells })
+        } else {
+            None
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(3, 2), Grid::new(Size::new(3, 2), 0).size());
+    /// ```
+    pub fn size(&self) -> Size<usize> {
+        self.size
+    }
+
+    /// Returns the rect covering all the cells, which is at the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(0, 0), Size::new(3, 2)),
+    ///     Grid::new(Size::new(3, 2), 0).bounds());
+    /// ```
+    pub fn bounds(&self) -> Rect<usize> {
+        Rect::new(Point::new(0, 0), self.size)
+    }
+
+    fn index_of(&self, point: Point<usize>) -> Option<usize> {
+        if point.x() < self.size.width() && point.y() < self.size.height() {
+            Some(point.y() * self.size.width() + point.x())
+        } else {
+            None
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::new(Size::new(3, 2), 'a');
+    /// assert_eq!(Some(&'a'), grid.get(Point::new(2, 1)));
+    /// assert_eq!(None, grid.get(Point::new(3, 1)));
+    /// ```
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(3, 2), 0);
+    /// *grid.get_mut(Point::new(2, 1)).unwrap() = 5;
+    /// assert_eq!(5, grid[Point::new(2, 1)]);
+    /// assert_eq!(None, grid.get_mut(Point::new(0, 2)));
+    /// ```
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        let i = self.index_of(point)?;
+        Some(&mut self.cells[i])
+    }
+
+    /// Iterates the cells with their points in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_vec(Size::new(2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(Some((Point::new(1, 1), &4)), grid.iter().last());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.bounds().points().zip(self.cells.iter())
+    }
+
+    /// Iterates the cells mutably with their points in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(2, 2), 0);
+    /// for (p, cell) in grid.iter_mut() {
+    ///     *cell = p.x() + p.y() * 2;
+    /// }
+    /// assert_eq!(&[0, 1, 2, 3], grid.cells());
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<usize>, &mut T)> {
+        self.bounds().points().zip(self.cells.iter_mut())
+    }
+
+    /// Returns the cells in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(3, 2), |p| p.x() * 10 + p.y());
+    /// assert_eq!(&[0, 10, 20, 1, 11, 21], grid.cells());
+    /// ```
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Size;
+    /// let grid = Grid::from_vec(Size::new(2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(vec![1, 2, 3, 4], grid.into_vec());
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Rotates a square grid by 90 degrees clockwise without allocating, like
+    /// `rotated_cw` does.
+    ///
+    /// # Panics
+    /// Panics if the grid is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Size;
+    /// let mut grid = Grid::from_vec(Size::new(2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// grid.rotate_square_cw();
+    /// assert_eq!(&[3, 1, 4, 2], grid.cells());
+    /// ```
+    pub fn rotate_square_cw(&mut self) {
+        let n = self.size.width();
+        assert_eq!(n, self.size.height(), "grid must be square");
+        for y in 0..n {
+            for x in y + 1..n {
+                self.cells.swap(y * n + x, x * n + y);
+            }
+        }
+        if n > 0 {
+            for row in self.cells.chunks_mut(n) {
+                row.reverse();
+            }
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// # Panics
+    /// Panics if the number of cells overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::new(Size::new(3, 2), 'a');
+    /// assert_eq!(6, grid.cells().len());
+    /// assert_eq!('a', grid[Point::new(2, 1)]);
+    /// ```
+    pub fn new(size: Size<usize>, value: T) -> Self {
+        let count = cell_count(size).expect("number of cells must not overflow");
+        Self {
+            size,
+            cells: vec![value; count],
+        }
+    }
+
+    /// Returns the grid transformed by `symmetry`, whose size is `symmetry.transform_size(size)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Symmetry;
+    /// let grid = Grid::from_vec(Size::new(3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let transformed = grid.transformed(Symmetry::ANTI_TRANSPOSE);
+    /// assert_eq!(Size::new(2, 3), transformed.size());
+    /// assert_eq!(&[6, 3, 5, 2, 4, 1], transformed.cells());
+    /// ```
+    pub fn transformed(&self, symmetry: Symmetry) -> Self {
+        let inverse = symmetry.inverse();
+        let size = symmetry.transform_size(self.size);
+        Self::from_fn(size, |p| self[inverse.transform_cell(p, size)].clone())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Size;
+    /// let grid = Grid::from_vec(Size::new(3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(&[4, 1, 5, 2, 6, 3], grid.rotated_cw().cells());
+    /// ```
+    pub fn rotated_cw(&self) -> Self {
+        self.transformed(Symmetry::ROTATE_90)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Size;
+    /// let grid = Grid::from_vec(Size::new(3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(&[3, 6, 2, 5, 1, 4], grid.rotated_ccw().cells());
+    /// ```
+    pub fn rotated_ccw(&self) -> Self {
+        self.transformed(Symmetry::ROTATE_270)
+    }
+
+    /// Returns the grid mirrored along `axis`, i.e. with reversed rows for `Axis::X`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let grid = Grid::from_vec(Size::new(3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(&[3, 2, 1, 6, 5, 4], grid.flipped(Axis::X).cells());
+    /// assert_eq!(&[4, 5, 6, 1, 2, 3], grid.flipped(Axis::Y).cells());
+    /// ```
+    pub fn flipped(&self, axis: Axis) -> Self {
+        self.transformed(match axis {
+            Axis::X => Symmetry::FLIP_X,
+            Axis::Y => Symmetry::FLIP_Y,
+        })
+    }
+
+    /// Returns the grid with x and y swapped, like `Transpose::transpose` does on each point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Size;
+    /// let grid = Grid::from_vec(Size::new(3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(&[1, 4, 2, 5, 3, 6], grid.transposed().cells());
+    /// ```
+    pub fn transposed(&self) -> Self {
+        self.transformed(Symmetry::TRANSPOSE)
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `point` is out of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(3, 2), |p| p.x() * 10 + p.y());
+    /// assert_eq!(21, grid[Point::new(2, 1)]);
+    /// ```
+    fn index(&self, point: Point<usize>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{} is out of the grid of {}", point, self.size),
+        }
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    /// # Panics
+    /// Panics if `point` is out of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(3, 2), 0);
+    /// grid[Point::new(1, 1)] = 7;
+    /// assert_eq!(&[0, 0, 0, 0, 7, 0], grid.cells());
+    /// ```
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let size = self.size;
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{} is out of the grid of {}", point, size),
+        }
+    }
+}
@@ -0,0 +1,567 @@
+use crate::component_wise::ComponentWise;
+use crate::grid::Grid;
+use crate::map::Map;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use std::ops::{Index, IndexMut};
+
+/// Returns `rect` clipped to `bounds`, which is empty if they do not intersect.
+fn clip(bounds: Rect<usize>, rect: Rect<usize>) -> Rect<usize> {
+    bounds.intersection(rect).unwrap_or_else(|| {
+        Rect::new(
+            rect.origin()
+                .component_max(bounds.min())
+                .component_min(bounds.max()),
+            Size::new(0, 0),
+        )
+    })
+}
+
+fn offset(origin: Point<usize>, local: Point<usize>) -> Point<usize> {
+    (origin, local).map(|(n, m)| n + m)
+}
+
+fn to_global(window: Rect<usize>, local: Point<usize>) -> Option<Point<usize>> {
+    if local.x() < window.size().width() && local.y() < window.size().height() {
+        Some(offset(window.origin(), local))
+    } else {
+        None
+    }
+}
+
+fn to_local(window: Rect<usize>, global: Point<usize>) -> Option<Point<usize>> {
+    if window.contains(global) {
+        Some((global, window.origin()).map(|(n, m)| n - m))
+    } else {
+        None
+    }
+}
+
+/// A borrowed rectangular window of a `Grid`, indexed by local points relative to its top left.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rect: Rect<usize>,
+}
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns the view of `rect` in `grid`, clipped to the bounds of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::GridView;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::new(Size::new(4, 3), 0);
+    /// let view = GridView::new(&grid, Rect::new(Point::new(2, 1), Size::new(5, 5)));
+    /// assert_eq!(Rect::new(Point::new(2, 1), Size::new(2, 2)), view.rect());
+    /// ```
+    pub fn new(grid: &'a Grid<T>, rect: Rect<usize>) -> Self {
+        Self {
+            grid,
+            rect: clip(grid.bounds(), rect),
+        }
+    }
+
+    /// Returns the window in global points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::new(Size::new(4, 3), 0);
+    /// let view = grid.view(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(Rect::new(Point::new(1, 1), Size::new(2, 2)), view.rect());
+    /// ```
+    pub fn rect(&self) -> Rect<usize> {
+        self.rect
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::new(Size::new(4, 3), 0);
+    /// let view = grid.view(Rect::new(Point::new(3, 1), Size::new(2, 2)));
+    /// assert_eq!(Size::new(1, 2), view.size());
+    /// ```
+    pub fn size(&self) -> Size<usize> {
+        self.rect.size()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(Some(&10), view.get(Point::new(1, 1)));
+    /// assert_eq!(None, view.get(Point::new(2, 0)));
+    /// ```
+    pub fn get(&self, local: Point<usize>) -> Option<&'a T> {
+        self.grid.get(to_global(self.rect, local)?)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(Some(&10), view.get_global(Point::new(2, 2)));
+    /// assert_eq!(None, view.get_global(Point::new(0, 0)));
+    /// ```
+    pub fn get_global(&self, global: Point<usize>) -> Option<&'a T> {
+        self.get(to_local(self.rect, global)?)
+    }
+
+    /// Returns the view of `rect` in local points, clipped to this view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::new(Size::new(10, 10), 0);
+    /// let view = grid.view(Rect::new(Point::new(2, 3), Size::new(4, 4)));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(5, 4), Size::new(1, 3)),
+    ///     view.view(Rect::new(Point::new(3, 1), Size::new(5, 5))).rect());
+    /// ```
+    pub fn view(&self, rect: Rect<usize>) -> GridView<'a, T> {
+        let global = Rect::new(offset(self.rect.origin(), rect.origin()), rect.size());
+        Self {
+            grid: self.grid,
+            rect: clip(self.rect, global),
+        }
+    }
+
+    /// Iterates the cells with their local points in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(vec![5, 6, 9, 10], view.iter().map(|(_, &n)| n).collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &'a T)> + 'a {
+        let (grid, origin) = (self.grid, self.rect.origin());
+        Rect::new(Point::new(0, 0), self.size())
+            .points()
+            .map(move |p| (p, &grid[offset(origin, p)]))
+    }
+}
+
+impl<'a, T> Index<Point<usize>> for GridView<'a, T> {
+    type Output = T;
+
+    /// Returns the cell at the local `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(9, view[Point::new(0, 1)]);
+    /// ```
+    fn index(&self, point: Point<usize>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{} is out of the view of {}", point, self.size()),
+        }
+    }
+}
+
+/// A mutably borrowed rectangular window of a `Grid`, with the same points as `GridView`.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    rect: Rect<usize>,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// Returns the view of `rect` in `grid`, clipped to the bounds of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::GridViewMut;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// let view = GridViewMut::new(&mut grid, Rect::new(Point::new(2, 1), Size::new(5, 5)));
+    /// assert_eq!(Rect::new(Point::new(2, 1), Size::new(2, 2)), view.rect());
+    /// ```
+    pub fn new(grid: &'a mut Grid<T>, rect: Rect<usize>) -> Self {
+        let rect = clip(grid.bounds(), rect);
+        Self { grid, rect }
+    }
+
+    /// Returns the window in global points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// let view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(Rect::new(Point::new(1, 1), Size::new(2, 2)), view.rect());
+    /// ```
+    pub fn rect(&self) -> Rect<usize> {
+        self.rect
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// let view = grid.view_mut(Rect::new(Point::new(3, 1), Size::new(2, 2)));
+    /// assert_eq!(Size::new(1, 2), view.size());
+    /// ```
+    pub fn size(&self) -> Size<usize> {
+        self.rect.size()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(view.rect(), view.as_view().rect());
+    /// assert_eq!(Some(&10), view.as_view().get(Point::new(1, 1)));
+    /// ```
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            rect: self.rect,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(Some(&10), view.get(Point::new(1, 1)));
+    /// assert_eq!(None, view.get(Point::new(2, 0)));
+    /// ```
+    pub fn get(&self, local: Point<usize>) -> Option<&T> {
+        self.grid.get(to_global(self.rect, local)?)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// let mut view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// *view.get_mut(Point::new(1, 0)).unwrap() = 7;
+    /// assert_eq!(None, view.get_mut(Point::new(2, 0)));
+    /// assert_eq!(7, grid[Point::new(2, 1)]);
+    /// ```
+    pub fn get_mut(&mut self, local: Point<usize>) -> Option<&mut T> {
+        self.grid.get_mut(to_global(self.rect, local)?)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(Some(&10), view.get_global(Point::new(2, 2)));
+    /// assert_eq!(None, view.get_global(Point::new(0, 0)));
+    /// ```
+    pub fn get_global(&self, global: Point<usize>) -> Option<&T> {
+        self.get(to_local(self.rect, global)?)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// let mut view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// *view.get_global_mut(Point::new(2, 1)).unwrap() = 7;
+    /// assert_eq!(None, view.get_global_mut(Point::new(3, 1)));
+    /// assert_eq!(7, grid[Point::new(2, 1)]);
+    /// ```
+    pub fn get_global_mut(&mut self, global: Point<usize>) -> Option<&mut T> {
+        self.get_mut(to_local(self.rect, global)?)
+    }
+
+    /// Returns the view of `rect` in local points, clipped to this view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(10, 10), 0);
+    /// let view = grid.view_mut(Rect::new(Point::new(2, 3), Size::new(4, 4)));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(5, 4), Size::new(1, 3)),
+    ///     view.view(Rect::new(Point::new(3, 1), Size::new(5, 5))).rect());
+    /// ```
+    pub fn view(&self, rect: Rect<usize>) -> GridView<'_, T> {
+        self.as_view().view(rect)
+    }
+
+    /// Returns the mutable view of `rect` in local points, clipped to this view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 4), 0);
+    /// let mut view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(3, 3)));
+    /// view.view_mut(Rect::new(Point::new(1, 1), Size::new(9, 9))).fill(1);
+    /// assert_eq!(4, grid.iter().filter(|&(_, &n)| n == 1).count());
+    /// ```
+    pub fn view_mut(&mut self, rect: Rect<usize>) -> GridViewMut<'_, T> {
+        let global = Rect::new(offset(self.rect.origin(), rect.origin()), rect.size());
+        GridViewMut {
+            rect: clip(self.rect, global),
+            grid: self.grid,
+        }
+    }
+
+    /// Iterates the cells with their local points in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(vec![5, 6, 9, 10], view.iter().map(|(_, &n)| n).collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.as_view().iter()
+    }
+
+    /// Iterates the cells mutably with their local points in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// let mut view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// for (p, cell) in view.iter_mut() {
+    ///     *cell = p.x() + p.y() * 2 + 1;
+    /// }
+    /// assert_eq!(&[0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0], grid.cells());
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<usize>, &mut T)> {
+        let (origin, size) = (self.rect.origin(), self.size());
+        let width = self.grid.size().width();
+        self.grid
+            .iter_mut()
+            .skip(origin.y() * width)
+            .take(size.height() * width)
+            .filter_map(move |(p, cell)| {
+                if origin.x() <= p.x() && p.x() < origin.x() + size.width() {
+                    Some(((p, origin).map(|(n, m)| n - m), cell))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(3, 3), 0);
+    /// grid.view_mut(Rect::new(Point::new(1, 0), Size::new(2, 2))).fill(1);
+    /// assert_eq!(&[0, 1, 1, 0, 1, 1, 0, 0, 0], grid.cells());
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for (_, cell) in self.iter_mut() {
+            *cell = value.clone();
+        }
+    }
+
+    /// Copies the cells of `src` to this view with the top left of `src` at the local `dst`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let stamp = Grid::new(Size::new(2, 2), 1);
+    /// let mut grid = Grid::new(Size::new(3, 3), 0);
+    /// grid.view_mut(grid.bounds()).blit(stamp.view(stamp.bounds()), Point::new(2, 1));
+    /// assert_eq!(&[0, 0, 0, 0, 0, 1, 0, 0, 1], grid.cells());
+    /// ```
+    pub fn blit(&mut self, src: GridView<'_, T>, dst: Point<usize>)
+    where
+        T: Clone,
+    {
+        self.blit_with(src, dst, |cell, src| *cell = src.clone());
+    }
+
+    /// Merges the cells of `src` into this view with `merge`, like `blit`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let stamp = Grid::new(Size::new(2, 2), 5);
+    /// let mut grid = Grid::new(Size::new(3, 3), 1);
+    /// let src = stamp.view(stamp.bounds());
+    /// grid.view_mut(grid.bounds()).blit_with(src, Point::new(0, 0), |cell, &n| *cell += n);
+    /// assert_eq!(&[6, 6, 1, 6, 6, 1, 1, 1, 1], grid.cells());
+    /// ```
+    pub fn blit_with<S>(
+        &mut self,
+        src: GridView<'_, S>,
+        dst: Point<usize>,
+        mut merge: impl FnMut(&mut T, &S),
+    ) {
+        for (p, cell) in src.iter() {
+            if let Some(target) = self.get_mut(offset(dst, p)) {
+                merge(target, cell);
+            }
+        }
+    }
+}
+
+impl<'a, T> Index<Point<usize>> for GridViewMut<'a, T> {
+    type Output = T;
+
+    /// Returns the cell at the local `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)));
+    /// assert_eq!(9, view[Point::new(0, 1)]);
+    /// ```
+    fn index(&self, point: Point<usize>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{} is out of the view of {}", point, self.size()),
+        }
+    }
+}
+
+impl<'a, T> IndexMut<Point<usize>> for GridViewMut<'a, T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// grid.view_mut(Rect::new(Point::new(1, 1), Size::new(2, 2)))[Point::new(1, 1)] = 7;
+    /// assert_eq!(7, grid[Point::new(2, 2)]);
+    /// ```
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let size = self.size();
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{} is out of the view of {}", point, size),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the view of `rect`, clipped to the bounds of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let grid = Grid::from_fn(Size::new(4, 3), |p| p.x() + p.y() * 4);
+    /// let view = grid.view(Rect::new(Point::new(3, 2), Size::new(2, 2)));
+    /// assert_eq!(Size::new(1, 1), view.size());
+    /// assert_eq!(11, view[Point::new(0, 0)]);
+    /// ```
+    pub fn view(&self, rect: Rect<usize>) -> GridView<'_, T> {
+        GridView::new(self, rect)
+    }
+
+    /// Returns the mutable view of `rect`, clipped to the bounds of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Grid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = Grid::new(Size::new(4, 3), 0);
+    /// grid.view_mut(Rect::new(Point::new(3, 2), Size::new(2, 2))).fill(1);
+    /// assert_eq!(1, grid.cells().iter().sum::<i32>());
+    /// ```
+    pub fn view_mut(&mut self, rect: Rect<usize>) -> GridViewMut<'_, T> {
+        GridViewMut::new(self, rect)
+    }
+}
@@ -0,0 +1,458 @@
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::size::Size;
+use num;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A hexagon in axial coordinates, where the third cube coordinate is `s = -q - r`.
+///
+/// The six directions of `Hex::directions()` start from `+q` and go counterclockwise as seen on a
+/// y-down screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hex<T: Primitive> {
+    q: T,
+    r: T,
+}
+
+impl<T: Primitive> Hex<T> {
+    pub fn new(q: T, r: T) -> Self {
+        Self { q, r }
+    }
+
+    pub fn q(self) -> T {
+        self.q
+    }
+
+    pub fn r(self) -> T {
+        self.r
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(Some(Hex::new(3.0, -2.0)), Hex::new(3, -2).cast::<f64>());
+    /// ```
+    pub fn cast<V: Primitive + num::NumCast>(self) -> Option<Hex<V>>
+    where
+        T: num::ToPrimitive,
+    {
+        Some(Hex::new(V::from(self.q)?, V::from(self.r)?))
+    }
+}
+
+impl<T: Primitive + num::Signed> Hex<T> {
+    /// Returns the hex from cube coordinates, ignoring `s` which is implied by `q` and `r`.
+    pub fn from_cube(q: T, r: T, _s: T) -> Self {
+        Self::new(q, r)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(-1, Hex::new(3, -2).s());
+    /// ```
+    pub fn s(self) -> T {
+        -self.q - self.r
+    }
+
+    pub fn to_cube(self) -> (T, T, T) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn directions() -> [Self; 6] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            Self::new(one, zero),
+            Self::new(one, -one),
+            Self::new(zero, -one),
+            Self::new(-one, zero),
+            Self::new(-one, one),
+            Self::new(zero, one),
+        ]
+    }
+
+    /// Returns the neighbor in `direction`, counted modulo 6 as in `Hex::directions()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(Hex::new(3, -3), Hex::new(2, -2).neighbor(1));
+    /// assert_eq!(Hex::new(3, -3), Hex::new(2, -2).neighbor(7));
+    /// ```
+    pub fn neighbor(self, direction: usize) -> Self {
+        self + Self::directions()[direction % 6]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(
+    ///     vec![Hex::new(1, 0), Hex::new(1, -1), Hex::new(0, -1), Hex::new(-1, 0), Hex::new(-1, 1), Hex::new(0, 1)],
+    ///     Hex::new(0, 0).neighbors().collect::<Vec<_>>());
+    /// ```
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        (0..6).map(move |direction| self.neighbor(direction))
+    }
+
+    /// Returns the number of steps between the hexes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(4, Hex::new(1, -2).distance(Hex::new(-3, 1)));
+    /// ```
+    pub fn distance(self, other: Self) -> T {
+        let d = self - other;
+        (d.q.abs() + d.r.abs() + d.s().abs()) / (T::one() + T::one())
+    }
+
+    /// Rotates the hex by 60 degrees counterclockwise on a y-down screen around the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(Hex::new(1, -1), Hex::new(1, 0).rotate_left());
+    /// assert_eq!(Hex::new(3, -2), Hex::new(3, -2).rotate_left().rotate_right());
+    /// ```
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    /// Rotates the hex by 60 degrees clockwise on a y-down screen around the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(Hex::new(0, 1), Hex::new(1, 0).rotate_right());
+    /// ```
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    /// Rotates the hex around `center` by `turns` times 60 degrees counterclockwise on a y-down
+    /// screen; negative turns rotate clockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(Hex::new(2, 0), Hex::new(2, 2).rotate_around(Hex::new(2, 1), 3));
+    /// assert_eq!(Hex::new(1, 1), Hex::new(2, 2).rotate_around(Hex::new(2, 1), -2));
+    /// ```
+    pub fn rotate_around(self, center: Self, turns: i32) -> Self {
+        let relative = self - center;
+        let rotated = (0..turns.rem_euclid(6)).fold(relative, |hex, _| hex.rotate_left());
+        center + rotated
+    }
+}
+
+impl<T: Primitive + num::PrimInt + num::Signed> Hex<T> {
+    fn scaled_direction(direction: usize, n: usize) -> Self {
+        Self::directions()[direction % 6] * num::cast(n).unwrap()
+    }
+
+    /// Iterates the hexes at exactly `radius` steps away, starting from the corner in direction 4
+    /// and walking around counterclockwise on a y-down screen.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(vec![Hex::new(3, 4)], Hex::new(3, 4).ring(0).collect::<Vec<_>>());
+    /// assert_eq!(
+    ///     vec![Hex::new(-1, 1), Hex::new(0, 1), Hex::new(1, 0), Hex::new(1, -1), Hex::new(0, -1), Hex::new(-1, 0)],
+    ///     Hex::new(0, 0).ring(1).collect::<Vec<_>>());
+    /// assert_eq!(12, Hex::new(0, 0).ring(2).count());
+    /// ```
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Self> {
+        (0..(radius * 6).max(1)).map(move |i| {
+            if radius == 0 {
+                return self;
+            }
+            let (side, step) = (i / radius, i % radius);
+            self + Self::scaled_direction(side + 4, radius) + Self::scaled_direction(side, step)
+        })
+    }
+
+    /// Iterates the hexes within `radius` steps, ring by ring from the center outwards.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(19, Hex::new(0, 0).spiral(2).count());
+    /// assert_eq!(Some(Hex::new(5, 5)), Hex::new(5, 5).spiral(2).next());
+    /// ```
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |n| self.ring(n))
+    }
+
+    /// Iterates the hexes on the line from this hex to `other`, both inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(
+    ///     vec![Hex::new(0, 0), Hex::new(1, 0), Hex::new(1, 1), Hex::new(2, 1)],
+    ///     Hex::new(0, 0).line_to(Hex::new(2, 1)).collect::<Vec<_>>());
+    /// ```
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = Self> {
+        let steps = self.distance(other).to_usize().unwrap();
+        // Nudged so that points exactly on an edge are consistently rounded to one side.
+        let from = Hex::new(
+            self.q.to_f64().unwrap() + 1e-6,
+            self.r.to_f64().unwrap() + 2e-6,
+        );
+        let to = Hex::new(
+            other.q.to_f64().unwrap() + 1e-6,
+            other.r.to_f64().unwrap() + 2e-6,
+        );
+        (0..=steps).map(move |i| {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                i as f64 / steps as f64
+            };
+            (from + (to - from) * t).round().cast().unwrap()
+        })
+    }
+
+    /// Converts the hex to the column and row of the offset coordinates `offset`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// # use coord::HexOffset;
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(1, 3), Hex::new(0, 3).to_offset(HexOffset::OddR));
+    /// assert_eq!(Point::new(2, 3), Hex::new(0, 3).to_offset(HexOffset::EvenR));
+    /// assert_eq!(Point::new(3, -1), Hex::new(3, -2).to_offset(HexOffset::OddQ));
+    /// assert_eq!(Point::new(3, 0), Hex::new(3, -2).to_offset(HexOffset::EvenQ));
+    /// ```
+    pub fn to_offset(self, offset: HexOffset) -> Point<T> {
+        let two = T::one() + T::one();
+        let parity = |n: T| n & T::one();
+        match offset {
+            HexOffset::OddR => Point::new(self.q + (self.r - parity(self.r)) / two, self.r),
+            HexOffset::EvenR => Point::new(self.q + (self.r + parity(self.r)) / two, self.r),
+            HexOffset::OddQ => Point::new(self.q, self.r + (self.q - parity(self.q)) / two),
+            HexOffset::EvenQ => Point::new(self.q, self.r + (self.q + parity(self.q)) / two),
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// # use coord::HexOffset;
+    /// # use coord::Point;
+    /// for &offset in &[HexOffset::OddR, HexOffset::EvenR, HexOffset::OddQ, HexOffset::EvenQ] {
+    ///     assert_eq!(Hex::new(-3, 5), Hex::from_offset(Hex::new(-3, 5).to_offset(offset), offset));
+    /// }
+    /// ```
+    pub fn from_offset(point: Point<T>, offset: HexOffset) -> Self {
+        let two = T::one() + T::one();
+        let parity = |n: T| n & T::one();
+        let (col, row) = (point.x(), point.y());
+        match offset {
+            HexOffset::OddR => Self::new(col - (row - parity(row)) / two, row),
+            HexOffset::EvenR => Self::new(col - (row + parity(row)) / two, row),
+            HexOffset::OddQ => Self::new(col, row - (col - parity(col)) / two),
+            HexOffset::EvenQ => Self::new(col, row - (col + parity(col)) / two),
+        }
+    }
+}
+
+impl<T: Primitive + num::Float> Hex<T> {
+    /// Rounds a fractional hex to the hex containing it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// assert_eq!(Hex::new(1.0, 0.0), Hex::new(0.6, 0.3).round());
+    /// assert_eq!(Hex::new(0.0, 1.0), Hex::new(0.3, 0.6).round());
+    /// ```
+    pub fn round(self) -> Self {
+        let (q, r, s) = (self.q, self.r, -self.q - self.r);
+        let (rq, rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            Self::new(-rr - rs, rr)
+        } else if dr > ds {
+            Self::new(rq, -rq - rs)
+        } else {
+            Self::new(rq, rr)
+        }
+    }
+}
+
+impl<T: Primitive + Add<T, Output = T>> Add for Hex<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl<T: Primitive + Sub<T, Output = T>> Sub for Hex<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl<T: Primitive + Neg<Output = T>> Neg for Hex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl<T: Primitive + Mul<T, Output = T>> Mul<T> for Hex<T> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        Self::new(self.q * n, self.r * n)
+    }
+}
+
+impl<T: Primitive + fmt::Display> fmt::Display for Hex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+/// The layouts of offset coordinates, named after which rows (`R`) or columns (`Q`) are shoved
+/// by half a hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOffset {
+    OddR,
+    EvenR,
+    OddQ,
+    EvenQ,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// Hexes with a corner at the top, arranged in rows.
+    Pointy,
+    /// Hexes with an edge at the top, arranged in columns.
+    Flat,
+}
+
+/// Maps hexes to pixels, with `size` being the distance from the center of a hex to its
+/// corners along each axis and `origin` the pixel of `Hex::new(0, 0)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HexLayout<T: Primitive> {
+    orientation: HexOrientation,
+    size: Size<T>,
+    origin: Point<T>,
+}
+
+impl<T: Primitive + num::Float> HexLayout<T> {
+    pub fn new(orientation: HexOrientation, size: Size<T>, origin: Point<T>) -> Self {
+        Self {
+            orientation,
+            size,
+            origin,
+        }
+    }
+
+    pub fn orientation(&self) -> HexOrientation {
+        self.orientation
+    }
+
+    pub fn size(&self) -> Size<T> {
+        self.size
+    }
+
+    pub fn origin(&self) -> Point<T> {
+        self.origin
+    }
+
+    fn constant(n: f64) -> T {
+        num::cast(n).unwrap()
+    }
+
+    /// Returns the pixel at the center of `hex`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// # use coord::HexLayout;
+    /// # use coord::HexOrientation;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let layout = HexLayout::new(HexOrientation::Flat, Size::new(10.0f32, 10.0), Point::new(100.0, 50.0));
+    /// assert_eq!(Point::new(130.0, 50.0), layout.to_point(Hex::new(2, -1)));
+    /// ```
+    pub fn to_point<H: Primitive + num::ToPrimitive>(&self, hex: Hex<H>) -> Point<T> {
+        let hex: Hex<T> = hex.cast().unwrap();
+        let sqrt3 = Self::constant(3.0).sqrt();
+        let half = Self::constant(0.5);
+        let one_half = Self::constant(1.5);
+        let (x, y) = match self.orientation {
+            HexOrientation::Pointy => (sqrt3 * hex.q + sqrt3 * half * hex.r, one_half * hex.r),
+            HexOrientation::Flat => (one_half * hex.q, sqrt3 * half * hex.q + sqrt3 * hex.r),
+        };
+        Point::new(
+            self.origin.x() + x * self.size.width(),
+            self.origin.y() + y * self.size.height(),
+        )
+    }
+
+    /// Returns the fractional hex at `point`; round it to find the hex containing the point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// # use coord::HexLayout;
+    /// # use coord::HexOrientation;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let layout = HexLayout::new(HexOrientation::Pointy, Size::new(8.0f32, 8.0), Point::new(0.0, 0.0));
+    /// let p = layout.to_point(Hex::new(-2, 3));
+    /// assert_eq!(Some(Hex::new(-2, 3)), layout.to_hex(Point::new(p.x() + 2.0, p.y() - 3.0)).round().cast());
+    /// ```
+    pub fn to_hex(&self, point: Point<T>) -> Hex<T> {
+        let x = (point.x() - self.origin.x()) / self.size.width();
+        let y = (point.y() - self.origin.y()) / self.size.height();
+        let sqrt3 = Self::constant(3.0).sqrt();
+        let third = Self::constant(3.0).recip();
+        let two_thirds = Self::constant(2.0) * third;
+        match self.orientation {
+            HexOrientation::Pointy => Hex::new(sqrt3 * third * x - third * y, two_thirds * y),
+            HexOrientation::Flat => Hex::new(two_thirds * x, -third * x + sqrt3 * third * y),
+        }
+    }
+
+    /// Returns the corners of `hex` in order of increasing angle from the x axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Hex;
+    /// # use coord::HexLayout;
+    /// # use coord::HexOrientation;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let layout = HexLayout::new(HexOrientation::Flat, Size::new(10.0f32, 10.0), Point::new(0.0, 0.0));
+    /// assert_eq!(Point::new(10.0, 0.0), layout.corners(Hex::new(0, 0))[0]);
+    /// ```
+    pub fn corners<H: Primitive + num::ToPrimitive>(&self, hex: Hex<H>) -> [Point<T>; 6] {
+        let center = self.to_point(hex);
+        let offset = match self.orientation {
+            HexOrientation::Pointy => Self::constant(0.5),
+            HexOrientation::Flat => T::zero(),
+        };
+        let mut corners = [center; 6];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let angle =
+                Self::constant(std::f64::consts::PI / 3.0) * (Self::constant(i as f64) + offset);
+            let (sin, cos) = angle.sin_cos();
+            *corner = Point::new(
+                center.x() + self.size.width() * cos,
+                center.y() + self.size.height() * sin,
+            );
+        }
+        corners
+    }
+}
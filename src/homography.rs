@@ -0,0 +1,178 @@
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::transform2d::Transform2D;
+use num;
+
+/// A projective transform as a 3x3 matrix applied to row vectors `[x, y, 1]`, followed by the
+/// division by the resulting `w`.
+///
+/// Like `Transform2D`, homographies are not tagged with units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Homography<T: Primitive> {
+    rows: [[T; 3]; 3],
+}
+
+pub type Transform3x3<T> = Homography<T>;
+
+impl<T: Primitive + num::Float> Homography<T> {
+    pub fn from_rows(rows: [[T; 3]; 3]) -> Self {
+        Self { rows }
+    }
+
+    pub fn rows(self) -> [[T; 3]; 3] {
+        self.rows
+    }
+
+    pub fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::from_rows([[one, zero, zero], [zero, one, zero], [zero, zero, one]])
+    }
+
+    /// Returns the transform mapping the unit square `(0, 0), (1, 0), (1, 1), (0, 1)` to `quad`,
+    /// or `None` if the quad is degenerate.
+    fn from_unit_square<U>(quad: [Point<T, U>; 4]) -> Option<Self> {
+        let [(x0, y0), (x1, y1), (x2, y2), (x3, y3)] = [
+            (quad[0].x(), quad[0].y()),
+            (quad[1].x(), quad[1].y()),
+            (quad[2].x(), quad[2].y()),
+            (quad[3].x(), quad[3].y()),
+        ];
+        let (dx1, dx2, sx) = (x1 - x2, x3 - x2, x0 - x1 + x2 - x3);
+        let (dy1, dy2, sy) = (y1 - y2, y3 - y2, y0 - y1 + y2 - y3);
+        let det = dx1 * dy2 - dx2 * dy1;
+        if det.is_zero() {
+            return None;
+        }
+        let g = (sx * dy2 - dx2 * sy) / det;
+        let h = (dx1 * sy - sx * dy1) / det;
+        let transform = Self::from_rows([
+            [x1 - x0 + g * x1, y1 - y0 + g * y1, g],
+            [x3 - x0 + h * x3, y3 - y0 + h * y3, h],
+            [x0, y0, T::one()],
+        ]);
+        if transform.is_singular() {
+            None
+        } else {
+            Some(transform)
+        }
+    }
+
+    /// Returns the transform mapping each corner of `from` to the corresponding corner of `to`,
+    /// or `None` if either quad is degenerate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Homography;
+    /// # use coord::Point;
+    /// # use coord::Map;
+    /// let from = [Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0), Point::new(0.0, 4.0)];
+    /// let to = [Point::new(1.0, 1.0), Point::new(9.0, 2.0), Point::new(7.0, 6.0), Point::new(2.0, 5.0)];
+    /// let h = Homography::from_quads(from, to).unwrap();
+    /// for (&p, &q) in from.iter().zip(to.iter()) {
+    ///     assert_eq!(Some(q), h.transform_point(p).map(|p| p.map(|n: f64| (n * 1e9).round() / 1e9)));
+    /// }
+    /// ```
+    pub fn from_quads<U>(from: [Point<T, U>; 4], to: [Point<T, U>; 4]) -> Option<Self> {
+        let from = Self::from_unit_square(from)?.inverse()?;
+        Some(from.then(Self::from_unit_square(to)?))
+    }
+
+    /// Returns the transform applying this transform and then `other`.
+    pub fn then(self, other: Self) -> Self {
+        let mut rows = [[T::zero(); 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, n) in row.iter_mut().enumerate() {
+                *n = (0..3).fold(T::zero(), |sum, k| sum + self.rows[i][k] * other.rows[k][j]);
+            }
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Returns whether the determinant vanishes relative to the product of the row lengths, which
+    /// bounds it.
+    fn is_singular(self) -> bool {
+        let bound = self.rows.iter().fold(T::one(), |product, row| {
+            product * row.iter().fold(T::zero(), |sum, &n| sum + n * n).sqrt()
+        });
+        self.determinant().abs() <= bound * T::epsilon().sqrt()
+    }
+
+    pub fn determinant(self) -> T {
+        let m = self.rows;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Returns the inverse transform, or `None` if the transform is not invertible.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Homography;
+    /// # use coord::Point;
+    /// let h = Homography::from_rows([[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [1.0, -1.0, 1.0]]);
+    /// assert_eq!(Some(Point::new(7.0, 15.0)), h.transform_point(Point::new(3.0, 4.0)));
+    /// assert_eq!(Some(Point::new(3.0, 4.0)), h.inverse().unwrap().transform_point(Point::new(7.0, 15.0)));
+    /// ```
+    pub fn inverse(self) -> Option<Self> {
+        if self.is_singular() {
+            return None;
+        }
+        let det = self.determinant();
+        let m = self.rows;
+        let mut rows = [[T::zero(); 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, n) in row.iter_mut().enumerate() {
+                let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+                let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+                *n = (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / det;
+            }
+        }
+        Some(Self::from_rows(rows))
+    }
+
+    /// Transforms `point`, or returns `None` if it is mapped to infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Homography;
+    /// # use coord::Point;
+    /// let h = Homography::from_rows([[1.0, 0.0, 1.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    /// assert_eq!(Some(Point::new(0.75, 1.0)), h.transform_point(Point::new(3.0, 4.0)));
+    /// assert_eq!(None, h.transform_point(Point::new(-1.0, 4.0)));
+    /// ```
+    pub fn transform_point<U>(self, point: Point<T, U>) -> Option<Point<T, U>> {
+        let m = self.rows;
+        let (x, y) = (point.x(), point.y());
+        let w = x * m[0][2] + y * m[1][2] + m[2][2];
+        if w.abs() <= T::epsilon() {
+            return None;
+        }
+        Some(Point::typed(
+            (x * m[0][0] + y * m[1][0] + m[2][0]) / w,
+            (x * m[0][1] + y * m[1][1] + m[2][1]) / w,
+        ))
+    }
+}
+
+impl<T: Primitive + num::Float> Default for Homography<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Primitive + num::Float> From<Transform2D<T>> for Homography<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Homography;
+    /// # use coord::Transform2D;
+    /// # use coord::Point;
+    /// let t = Transform2D::scale(2.0, 3.0).then_scale(1.0, 2.0);
+    /// assert_eq!(Some(t.transform_point(Point::new(3.0, 4.0))), Homography::from(t).transform_point(Point::new(3.0, 4.0)));
+    /// ```
+    fn from(transform: Transform2D<T>) -> Self {
+        let [m11, m12, m21, m22, m31, m32] = transform.to_array();
+        let (zero, one) = (T::zero(), T::one());
+        Self::from_rows([[m11, m12, zero], [m21, m22, zero], [m31, m32, one]])
+    }
+}
@@ -1,35 +1,127 @@
+#[macro_use]
+pub mod unit;
+pub mod align;
 pub mod angle;
 pub mod axis;
+pub mod axis3;
+pub mod bounding_box;
+pub mod box3;
+pub mod box_n;
+pub mod bsp;
+pub mod chunk;
+pub mod chunked_grid;
+pub mod component_wise;
 pub mod coord;
+pub mod coord3;
+pub mod coord_n;
+pub mod direction;
 pub mod expand_by;
+pub mod grid;
+pub mod grid_view;
+pub mod hex;
+pub mod homography;
 pub mod map;
+pub mod map3;
 pub mod move_by;
+pub mod nine_slice;
 pub mod ops;
+pub mod ops3;
+pub mod ops_n;
+pub mod packing;
 pub mod point;
+pub mod point3;
+pub mod point_n;
+pub mod point_n_range_iterator;
 pub mod points;
 pub mod rect;
+pub mod scale;
+pub mod side_offsets;
 pub mod size;
+pub mod size3;
+pub mod size_n;
+pub mod sparse_grid;
+pub mod split;
+pub mod symmetry;
+pub mod transform2d;
 pub mod transpose;
 pub mod tuple;
+pub mod tuple3;
 pub mod vector;
+pub mod vector3;
+pub mod vector_n;
+pub mod viewport;
+pub mod wrapped_space;
+pub mod y_direction;
 pub mod point_range;
 pub mod point_range_iterator;
 
 pub mod prelude {
+    pub use crate::align::Align;
+    pub use crate::align::Alignment;
+    pub use crate::align::Letterbox;
     pub use crate::angle::Angle;
     pub use crate::angle::AnglePrimitive;
     pub use crate::axis::Axis;
     pub use crate::axis::AxisKeyed;
+    pub use crate::axis3::Axis3;
+    pub use crate::axis3::AxisKeyed3;
+    pub use crate::bounding_box::BoundingBox;
+    pub use crate::box3::Box3;
+    pub use crate::box_n::BoxN;
+    pub use crate::bsp::Bsp;
+    pub use crate::bsp::BspNode;
+    pub use crate::bsp::BspSplitter;
+    pub use crate::chunked_grid::ChunkedGrid;
+    pub use crate::component_wise::ComponentWise;
     pub use crate::coord::Coord;
     pub use crate::coord::Primitive;
+    pub use crate::coord3::Coord3;
+    pub use crate::coord_n::CoordN;
+    pub use crate::coord_n::MapN;
+    pub use crate::direction::Direction4;
+    pub use crate::direction::Direction8;
     pub use crate::expand_by::ExpandBy;
+    pub use crate::grid::Grid;
+    pub use crate::grid_view::GridView;
+    pub use crate::grid_view::GridViewMut;
+    pub use crate::hex::Hex;
+    pub use crate::hex::HexLayout;
+    pub use crate::hex::HexOffset;
+    pub use crate::hex::HexOrientation;
+    pub use crate::homography::Homography;
+    pub use crate::homography::Transform3x3;
     pub use crate::map::Map;
+    pub use crate::map3::Map3;
     pub use crate::move_by::MoveBy;
+    pub use crate::nine_slice::NineSlice;
+    pub use crate::packing::MaxRectsPacker;
+    pub use crate::packing::PackResult;
+    pub use crate::packing::Packer;
+    pub use crate::packing::Placement;
+    pub use crate::packing::SkylinePacker;
     pub use crate::point::Point;
+    pub use crate::point3::Point3;
+    pub use crate::point_n::PointN;
+    pub use crate::point_n_range_iterator::PointNRangeIterator;
+    pub use crate::point_n_range_iterator::PointNRangeIteratorInclusive;
     pub use crate::rect::Rect;
+    pub use crate::scale::Scale;
+    pub use crate::side_offsets::Margins;
+    pub use crate::side_offsets::SideOffsets;
     pub use crate::size::Size;
+    pub use crate::size3::Size3;
+    pub use crate::size_n::SizeN;
+    pub use crate::sparse_grid::SparseGrid;
+    pub use crate::transform2d::Transform2D;
+    pub use crate::symmetry::Symmetry;
     pub use crate::transpose::Transpose;
+    pub use crate::unit::UnknownUnit;
     pub use crate::vector::Vector;
+    pub use crate::vector3::Vector3;
+    pub use crate::vector_n::VectorN;
+    pub use crate::viewport::Viewport;
+    pub use crate::wrapped_space::WrappedSpace;
+    pub use crate::y_direction::YDirection;
     pub use crate::point_range::PointRangeBounds;
     pub use crate::point_range::PointRange;
     pub use crate::point_range_iterator::PointRangeIterator;
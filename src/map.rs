@@ -7,7 +7,7 @@ pub trait Map: Coord {
     /// # use coord::Map;
     /// assert_eq!(Vector::new(8, 6), Vector::new(4, 3).map(|n| n * 2));
     /// ```
-    fn map<R: Coord>(&self, mut f: impl FnMut(Self::Item) -> <R as Coord>::Item) -> R {
+    fn map<R: Coord<Unit = Self::Unit>>(&self, mut f: impl FnMut(Self::Item) -> <R as Coord>::Item) -> R {
         R::from_x_y(f(self.x()), f(self.y()))
     }
 }
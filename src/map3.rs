@@ -0,0 +1,18 @@
+use crate::coord3::Coord3;
+
+pub trait Map3: Coord3 {
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// # use coord::Map3;
+    /// assert_eq!(Vector3::new(8, 6, 4), Vector3::new(4, 3, 2).map(|n| n * 2));
+    /// ```
+    fn map<R: Coord3<Unit = Self::Unit>>(
+        &self,
+        mut f: impl FnMut(Self::Item) -> <R as Coord3>::Item,
+    ) -> R {
+        R::from_x_y_z(f(self.x()), f(self.y()), f(self.z()))
+    }
+}
+
+impl<T: Coord3> Map3 for T {}
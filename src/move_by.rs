@@ -1,16 +1,17 @@
 use crate::coord::Primitive;
 use crate::point::Point;
 use crate::rect::Rect;
+use crate::unit::UnknownUnit;
 use crate::vector::Vector;
 use std::ops::Add;
 
-pub trait MoveBy<T: Primitive> {
-    fn move_by(self, vector: Vector<T>) -> Self;
+pub trait MoveBy<T: Primitive, U = UnknownUnit> {
+    fn move_by(self, vector: Vector<T, U>) -> Self;
 }
 
-impl<T: Primitive, VP: Primitive> MoveBy<VP> for Point<T>
+impl<T: Primitive, VP: Primitive, U> MoveBy<VP, U> for Point<T, U>
 where
-    Point<T>: Add<Vector<VP>, Output = Point<T>>,
+    Point<T, U>: Add<Vector<VP, U>, Output = Point<T, U>>,
 {
     /// # Examples
     /// ```
@@ -19,14 +20,14 @@ where
     /// # use coord::MoveBy;
     /// assert_eq!(Point::new(15, 17), Point::new(5, 7).move_by(Vector::new(10, 10)));
     /// ```
-    fn move_by(self, vector: Vector<VP>) -> Self {
+    fn move_by(self, vector: Vector<VP, U>) -> Self {
         self + vector
     }
 }
 
-impl<T: Primitive, VP: Primitive> MoveBy<VP> for Rect<T>
+impl<T: Primitive, VP: Primitive, U> MoveBy<VP, U> for Rect<T, U>
 where
-    Point<T>: MoveBy<VP>,
+    Point<T, U>: MoveBy<VP, U>,
 {
     /// # Examples
     /// ```
@@ -37,12 +38,12 @@ where
     /// # use coord::MoveBy;
     /// assert_eq!(Rect::new(Point::new(12, 24), Size::new(8, 9)), Rect::new(Point::new(10, 20), Size::new(8, 9)).move_by(Vector::new(2, 4)));
     /// ```
-    fn move_by(self, vector: Vector<VP>) -> Self {
-        Self::new(self.origin().move_by(vector), self.size())
+    fn move_by(self, vector: Vector<VP, U>) -> Self {
+        Self::typed(self.origin().move_by(vector), self.size())
     }
 }
 
-impl<VP: Primitive, T: MoveBy<VP>> MoveBy<VP> for (T,) {
+impl<VP: Primitive, U, T: MoveBy<VP, U>> MoveBy<VP, U> for (T,) {
     /// # Examples
     /// ```
     /// # use coord::Point;
@@ -50,12 +51,12 @@ impl<VP: Primitive, T: MoveBy<VP>> MoveBy<VP> for (T,) {
     /// # use coord::MoveBy;
     /// assert_eq!((Point::new(15, 27),), (Point::new(5, 7),).move_by(Vector::new(10, 20)));
     /// ```
-    fn move_by(self, vector: Vector<VP>) -> Self {
+    fn move_by(self, vector: Vector<VP, U>) -> Self {
         (self.0.move_by(vector),)
     }
 }
 
-impl<VP: Primitive, T0: MoveBy<VP>, T1: MoveBy<VP>> MoveBy<VP> for (T0, T1) {
+impl<VP: Primitive, U, T0: MoveBy<VP, U>, T1: MoveBy<VP, U>> MoveBy<VP, U> for (T0, T1) {
     /// # Examples
     /// ```
     /// # use coord::Point;
@@ -67,12 +68,14 @@ impl<VP: Primitive, T0: MoveBy<VP>, T1: MoveBy<VP>> MoveBy<VP> for (T0, T1) {
     ///     (Point::new(11, 22), Rect::new(Point::new(13, 24), Size::new(5, 6))),
     ///     (Point::new(1, 2), Rect::new(Point::new(3, 4), Size::new(5, 6))).move_by(Vector::new(10, 20)));
     /// ```
-    fn move_by(self, vector: Vector<VP>) -> Self {
+    fn move_by(self, vector: Vector<VP, U>) -> Self {
         (self.0.move_by(vector), self.1.move_by(vector))
     }
 }
 
-impl<VP: Primitive, T0: MoveBy<VP>, T1: MoveBy<VP>, T2: MoveBy<VP>> MoveBy<VP> for (T0, T1, T2) {
+impl<VP: Primitive, U, T0: MoveBy<VP, U>, T1: MoveBy<VP, U>, T2: MoveBy<VP, U>> MoveBy<VP, U>
+    for (T0, T1, T2)
+ {
     /// # Examples
     /// ```
     /// # use coord::Point;
@@ -82,7 +85,7 @@ impl<VP: Primitive, T0: MoveBy<VP>, T1: MoveBy<VP>, T2: MoveBy<VP>> MoveBy<VP> f
     ///     (Point::new(11, 22), Point::new(13, 24), Point::new(15, 26)),
     ///     (Point::new(1, 2), Point::new(3, 4), Point::new(5, 6)).move_by(Vector::new(10, 20)));
     /// ```
-    fn move_by(self, vector: Vector<VP>) -> Self {
+    fn move_by(self, vector: Vector<VP, U>) -> Self {
         (
             self.0.move_by(vector),
             self.1.move_by(vector),
@@ -0,0 +1,191 @@
+use crate::coord::{Coord, Primitive};
+use crate::rect::Rect;
+use crate::side_offsets::SideOffsets;
+use crate::unit::UnknownUnit;
+use num;
+
+fn bands<T: num::Num + PartialOrd + Copy>(origin: T, size: T, start: T, end: T) -> [(T, T); 3] {
+    let (start, end) = if size < start + end {
+        let start = size * start / (start + end);
+        (start, size - start)
+    } else {
+        (start, end)
+    };
+    [
+        (origin, start),
+        (origin + start, size - start - end),
+        (origin + size - end, end),
+    ]
+}
+
+pub struct NineSlice<T: Primitive, U = UnknownUnit> {
+    rects: [Rect<T, U>; 9],
+}
+
+impl_unit_traits!(NineSlice { rects });
+
+impl<T: Primitive, U> NineSlice<T, U> {
+    /// Returns the nine rects in row-major order, from the top left to the bottom right.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(slice.center(), slice.rects()[4]);
+    /// ```
+    pub fn rects(&self) -> [Rect<T, U>; 9] {
+        self.rects
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(0, 0), Size::new(8, 2)), slice.top_left());
+    /// ```
+    pub fn top_left(&self) -> Rect<T, U> {
+        self.rects[0]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(8, 0), Size::new(18, 2)), slice.top());
+    /// ```
+    pub fn top(&self) -> Rect<T, U> {
+        self.rects[1]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(26, 0), Size::new(4, 2)), slice.top_right());
+    /// ```
+    pub fn top_right(&self) -> Rect<T, U> {
+        self.rects[2]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(0, 2), Size::new(8, 12)), slice.left());
+    /// ```
+    pub fn left(&self) -> Rect<T, U> {
+        self.rects[3]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(8, 2), Size::new(18, 12)), slice.center());
+    /// ```
+    pub fn center(&self) -> Rect<T, U> {
+        self.rects[4]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(26, 2), Size::new(4, 12)), slice.right());
+    /// ```
+    pub fn right(&self) -> Rect<T, U> {
+        self.rects[5]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(0, 14), Size::new(8, 6)), slice.bottom_left());
+    /// ```
+    pub fn bottom_left(&self) -> Rect<T, U> {
+        self.rects[6]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(8, 14), Size::new(18, 6)), slice.bottom());
+    /// ```
+    pub fn bottom(&self) -> Rect<T, U> {
+        self.rects[7]
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(26, 14), Size::new(4, 6)), slice.bottom_right());
+    /// ```
+    pub fn bottom_right(&self) -> Rect<T, U> {
+        self.rects[8]
+    }
+}
+
+impl<T: Primitive + num::Num + PartialOrd, U> Rect<T, U> {
+    /// Slices the rect by `borders`, shrinking them in proportion when they do not fit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(30, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(0, 0), Size::new(8, 2)), slice.top_left());
+    /// assert_eq!(Rect::new(Point::new(8, 2), Size::new(18, 12)), slice.center());
+    /// assert_eq!(Rect::new(Point::new(26, 14), Size::new(4, 6)), slice.bottom_right());
+    ///
+    /// let slice = Rect::new(Point::new(0, 0), Size::new(6, 20)).nine_slice(SideOffsets::new(2, 4, 6, 8));
+    /// assert_eq!(Rect::new(Point::new(0, 2), Size::new(4, 12)), slice.left());
+    /// assert_eq!(Rect::new(Point::new(4, 2), Size::new(0, 12)), slice.center());
+    /// assert_eq!(Rect::new(Point::new(4, 2), Size::new(2, 12)), slice.right());
+    /// ```
+    pub fn nine_slice(self, borders: SideOffsets<T>) -> NineSlice<T, U> {
+        let (x, width) = self.x();
+        let (y, height) = self.y();
+        let columns = bands(x, width, borders.left(), borders.right());
+        let rows = bands(y, height, borders.top(), borders.bottom());
+        let mut rects = [self; 9];
+        for (i, rect) in rects.iter_mut().enumerate() {
+            *rect = Rect::from_x_y(columns[i % 3], rows[i / 3]);
+        }
+        NineSlice { rects }
+    }
+}
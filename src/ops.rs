@@ -5,10 +5,10 @@ use crate::size::Size;
 use crate::vector::Vector;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
-impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>> Add<Vector<RHSP>>
-    for Point<T>
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, U> Add<Vector<RHSP, U>>
+    for Point<T, U>
 {
-    type Output = Point<<T as Add<RHSP>>::Output>;
+    type Output = Point<<T as Add<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
@@ -16,14 +16,14 @@ impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>> Add<Vec
     /// # use coord::Vector;
     /// assert_eq!(Point::new(17, 29), Point::new(10, 20) + Vector::new(7, 9));
     /// ```
-    fn add(self, rhs: Vector<RHSP>) -> Self::Output {
+    fn add(self, rhs: Vector<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(p, v)| p + v)
     }
 }
 
-impl<T: Primitive, RHS> AddAssign<RHS> for Point<T>
+impl<T: Primitive, U, RHS> AddAssign<RHS> for Point<T, U>
 where
-    Point<T>: Add<RHS, Output = Self>,
+    Point<T, U>: Add<RHS, Output = Self>,
 {
     /// # Examples
     /// ```
@@ -38,10 +38,10 @@ where
     }
 }
 
-impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Vector<RHSP>>
-    for Point<T>
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Vector<RHSP, U>>
+    for Point<T, U>
 {
-    type Output = Point<<T as Sub<RHSP>>::Output>;
+    type Output = Point<<T as Sub<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
@@ -49,14 +49,14 @@ impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Vec
     /// # use coord::Vector;
     /// assert_eq!(Point::new(4, 7), Point::new(14, 27) - Vector::new(10, 20));
     /// ```
-    fn sub(self, rhs: Vector<RHSP>) -> Self::Output {
+    fn sub(self, rhs: Vector<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(p, v)| p - v)
     }
 }
 
-impl<T: Primitive, RHS> SubAssign<RHS> for Point<T>
+impl<T: Primitive, U, RHS> SubAssign<RHS> for Point<T, U>
 where
-    Point<T>: Sub<RHS, Output = Self>,
+    Point<T, U>: Sub<RHS, Output = Self>,
 {
     /// # Examples
     /// ```
@@ -71,10 +71,10 @@ where
     }
 }
 
-impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Point<RHSP>>
-    for Point<T>
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Point<RHSP, U>>
+    for Point<T, U>
 {
-    type Output = Vector<<T as Sub<RHSP>>::Output>;
+    type Output = Vector<<T as Sub<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
@@ -82,15 +82,15 @@ impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Poi
     /// # use coord::Vector;
     /// assert_eq!(Vector::new(5, 8), Point::new(15, 28) - Point::new(10, 20));
     /// ```
-    fn sub(self, rhs: Point<RHSP>) -> Self::Output {
+    fn sub(self, rhs: Point<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(p, rhs)| p - rhs)
     }
 }
 
-impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>> Add<Size<RHSP>>
-    for Point<T>
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, U> Add<Size<RHSP, U>>
+    for Point<T, U>
 {
-    type Output = Point<<T as Add<RHSP>>::Output>;
+    type Output = Point<<T as Add<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
@@ -98,15 +98,15 @@ impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>> Add<Siz
     /// # use coord::Size;
     /// assert_eq!(Point::new(13, 24), Point::new(3, 4) + Size::new(10, 20));
     /// ```
-    fn add(self, rhs: Size<RHSP>) -> Self::Output {
+    fn add(self, rhs: Size<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(p, s)| p + s)
     }
 }
 
-impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Size<RHSP>>
-    for Point<T>
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Size<RHSP, U>>
+    for Point<T, U>
 {
-    type Output = Point<<T as Sub<RHSP>>::Output>;
+    type Output = Point<<T as Sub<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
@@ -114,7 +114,7 @@ impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Siz
     /// # use coord::Size;
     /// assert_eq!(Point::new(3, 4), Point::new(13, 24) - Size::new(10, 20));
     /// ```
-    fn sub(self, rhs: Size<RHSP>) -> Self::Output {
+    fn sub(self, rhs: Size<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(p, s)| p - s)
     }
 }
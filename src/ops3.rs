@@ -0,0 +1,120 @@
+use crate::coord::Primitive;
+use crate::map3::Map3;
+use crate::point3::Point3;
+use crate::size3::Size3;
+use crate::vector3::Vector3;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, U> Add<Vector3<RHSP, U>>
+    for Point3<T, U>
+{
+    type Output = Point3<<T as Add<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// assert_eq!(Point3::new(17, 29, 46), Point3::new(10, 20, 30) + Vector3::new(7, 9, 16));
+    /// ```
+    fn add(self, rhs: Vector3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(p, v)| p + v)
+    }
+}
+
+impl<T: Primitive, U, RHS> AddAssign<RHS> for Point3<T, U>
+where
+    Point3<T, U>: Add<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// let mut p = Point3::new(10, 20, 30);
+    /// p += Vector3::new(7, 9, 16);
+    /// assert_eq!(Point3::new(17, 29, 46), p);
+    /// ```
+    fn add_assign(&mut self, rhs: RHS) {
+        *self = *self + rhs;
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Vector3<RHSP, U>>
+    for Point3<T, U>
+{
+    type Output = Point3<<T as Sub<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// assert_eq!(Point3::new(4, 7, 11), Point3::new(14, 27, 41) - Vector3::new(10, 20, 30));
+    /// ```
+    fn sub(self, rhs: Vector3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(p, v)| p - v)
+    }
+}
+
+impl<T: Primitive, U, RHS> SubAssign<RHS> for Point3<T, U>
+where
+    Point3<T, U>: Sub<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// let mut p = Point3::new(14, 27, 41);
+    /// p -= Vector3::new(10, 20, 30);
+    /// assert_eq!(Point3::new(4, 7, 11), p);
+    /// ```
+    fn sub_assign(&mut self, rhs: RHS) {
+        *self = *self - rhs;
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Point3<RHSP, U>>
+    for Point3<T, U>
+{
+    type Output = Vector3<<T as Sub<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// assert_eq!(Vector3::new(5, 8, 13), Point3::new(15, 28, 43) - Point3::new(10, 20, 30));
+    /// ```
+    fn sub(self, rhs: Point3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(p, rhs)| p - rhs)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, U> Add<Size3<RHSP, U>>
+    for Point3<T, U>
+{
+    type Output = Point3<<T as Add<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(Point3::new(13, 24, 37), Point3::new(3, 4, 7) + Size3::new(10, 20, 30));
+    /// ```
+    fn add(self, rhs: Size3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(p, s)| p + s)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Size3<RHSP, U>>
+    for Point3<T, U>
+{
+    type Output = Point3<<T as Sub<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Size3;
+    /// assert_eq!(Point3::new(3, 4, 7), Point3::new(13, 24, 37) - Size3::new(10, 20, 30));
+    /// ```
+    fn sub(self, rhs: Size3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(p, s)| p - s)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::coord::Primitive;
+use crate::coord_n::MapN;
+use crate::point_n::PointN;
+use crate::size_n::SizeN;
+use crate::vector_n::VectorN;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, const N: usize, U>
+    Add<VectorN<RHSP, N, U>> for PointN<T, N, U>
+{
+    type Output = PointN<<T as Add<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// assert_eq!(PointN::new([17, 29, 46, 34]), PointN::new([10, 20, 30, 20]) + VectorN::new([7, 9, 16, 14]));
+    /// ```
+    fn add(self, rhs: VectorN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(p, v)| p + v)
+    }
+}
+
+impl<T: Primitive, RHS, const N: usize, U> AddAssign<RHS> for PointN<T, N, U>
+where
+    PointN<T, N, U>: Add<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// let mut p = PointN::new([10, 20, 30, 20]);
+    /// p += VectorN::new([7, 9, 16, 14]);
+    /// assert_eq!(PointN::new([17, 29, 46, 34]), p);
+    /// ```
+    fn add_assign(&mut self, rhs: RHS) {
+        *self = *self + rhs;
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, const N: usize, U>
+    Sub<VectorN<RHSP, N, U>> for PointN<T, N, U>
+{
+    type Output = PointN<<T as Sub<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// assert_eq!(PointN::new([4, 7, 11, 8]), PointN::new([14, 27, 41, 28]) - VectorN::new([10, 20, 30, 20]));
+    /// ```
+    fn sub(self, rhs: VectorN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(p, v)| p - v)
+    }
+}
+
+impl<T: Primitive, RHS, const N: usize, U> SubAssign<RHS> for PointN<T, N, U>
+where
+    PointN<T, N, U>: Sub<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// let mut p = PointN::new([14, 27, 41, 28]);
+    /// p -= VectorN::new([10, 20, 30, 20]);
+    /// assert_eq!(PointN::new([4, 7, 11, 8]), p);
+    /// ```
+    fn sub_assign(&mut self, rhs: RHS) {
+        *self = *self - rhs;
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, const N: usize, U>
+    Sub<PointN<RHSP, N, U>> for PointN<T, N, U>
+{
+    type Output = VectorN<<T as Sub<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// assert_eq!(VectorN::new([5, 8, 13, 10]), PointN::new([15, 28, 43, 30]) - PointN::new([10, 20, 30, 20]));
+    /// ```
+    fn sub(self, rhs: PointN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(p, rhs)| p - rhs)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, const N: usize, U>
+    Add<SizeN<RHSP, N, U>> for PointN<T, N, U>
+{
+    type Output = PointN<<T as Add<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// assert_eq!(PointN::new([13, 24, 37, 26]), PointN::new([3, 4, 7, 6]) + SizeN::new([10, 20, 30, 20]));
+    /// ```
+    fn add(self, rhs: SizeN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(p, s)| p + s)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, const N: usize, U>
+    Sub<SizeN<RHSP, N, U>> for PointN<T, N, U>
+{
+    type Output = PointN<<T as Sub<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::SizeN;
+    /// assert_eq!(PointN::new([3, 4, 7, 6]), PointN::new([13, 24, 37, 26]) - SizeN::new([10, 20, 30, 20]));
+    /// ```
+    fn sub(self, rhs: SizeN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(p, s)| p - s)
+    }
+}
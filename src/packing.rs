@@ -0,0 +1,465 @@
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::transpose::Transpose;
+use num;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement<T: Primitive> {
+    rect: Rect<T>,
+    rotated: bool,
+}
+
+impl<T: Primitive> Placement<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// let placement = packer.insert(Size::new(4, 3)).unwrap();
+    /// assert_eq!(Rect::new(Point::new(0, 0), Size::new(4, 3)), placement.rect());
+    /// ```
+    pub fn rect(&self) -> Rect<T> {
+        self.rect
+    }
+
+    /// Returns whether the item has been rotated by 90°, so that the size of the rect is the
+    /// transposed size of the item.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::MaxRectsPacker;
+    /// # use coord::Size;
+    /// let mut packer = MaxRectsPacker::new(Size::new(10, 5)).allow_rotation(true);
+    /// let placement = packer.insert(Size::new(2, 8)).unwrap();
+    /// assert!(placement.rotated());
+    /// assert_eq!(Size::new(8, 2), placement.rect().size());
+    /// ```
+    pub fn rotated(&self) -> bool {
+        self.rotated
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackResult<T: Primitive> {
+    placements: Vec<(usize, Placement<T>)>,
+    failed: Vec<usize>,
+}
+
+impl<T: Primitive> PackResult<T> {
+    /// Returns the placements paired with the indices of the packed items, in index order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// let result = packer.pack(&[Size::new(6, 6), Size::new(5, 5), Size::new(4, 4)]);
+    /// let origins = result
+    ///     .placements()
+    ///     .iter()
+    ///     .map(|&(i, placement)| (i, placement.rect().origin()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec![(0, Point::new(0, 0)), (2, Point::new(6, 0))], origins);
+    /// ```
+    pub fn placements(&self) -> &[(usize, Placement<T>)] {
+        &self.placements
+    }
+
+    /// Returns the indices of the items which did not fit, in index order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// let result = packer.pack(&[Size::new(6, 6), Size::new(5, 5), Size::new(4, 4)]);
+    /// assert_eq!(&[1], result.failed());
+    /// ```
+    pub fn failed(&self) -> &[usize] {
+        &self.failed
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// let result = packer.pack(&[Size::new(6, 6), Size::new(5, 5), Size::new(4, 4)]);
+    /// assert!(!result.is_complete());
+    /// assert!(packer.pack(&[Size::new(4, 4)]).is_complete());
+    /// ```
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+fn orientations<T: Primitive + PartialEq>(
+    size: Size<T>,
+    allow_rotation: bool,
+) -> impl Iterator<Item = (Size<T>, bool)> {
+    let rotated = size.transpose();
+    let rotated = if allow_rotation && rotated != size {
+        Some((rotated, true))
+    } else {
+        None
+    };
+    Some((size, false)).into_iter().chain(rotated)
+}
+
+fn partial_lt<T: PartialOrd>(n: T, m: T) -> bool {
+    n.partial_cmp(&m) == Some(Ordering::Less)
+}
+
+pub trait Packer<T: Primitive + num::Num + PartialOrd> {
+    /// Places an item of `size`, or returns `None` if it does not fit in the remaining space.
+    fn insert(&mut self, size: Size<T>) -> Option<Placement<T>>;
+
+    /// Places the items from the largest to the smallest, which usually packs tighter than
+    /// inserting them in the given order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// let result = packer.pack(&[Size::new(4, 4), Size::new(6, 6), Size::new(4, 6)]);
+    /// assert!(result.is_complete());
+    /// ```
+    fn pack(&mut self, sizes: &[Size<T>]) -> PackResult<T> {
+        let mut order = (0..sizes.len()).collect::<Vec<_>>();
+        let key = |size: Size<T>| {
+            let (long, short) = if size.width() < size.height() {
+                (size.height(), size.width())
+            } else {
+                (size.width(), size.height())
+            };
+            (long, short)
+        };
+        order.sort_by(|&i, &j| {
+            key(sizes[j])
+                .partial_cmp(&key(sizes[i]))
+                .unwrap_or(Ordering::Equal)
+        });
+        let mut placements = Vec::new();
+        let mut failed = Vec::new();
+        for index in order {
+            match self.insert(sizes[index]) {
+                Some(placement) => placements.push((index, placement)),
+                None => failed.push(index),
+            }
+        }
+        placements.sort_by_key(|&(index, _)| index);
+        failed.sort_unstable();
+        PackResult { placements, failed }
+    }
+}
+
+/// A packer which keeps the top edge of the used space as a skyline and places each item as
+/// low as possible on it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SkylinePacker<T: Primitive> {
+    size: Size<T>,
+    allow_rotation: bool,
+    skyline: Vec<(T, T, T)>,
+}
+
+impl<T: Primitive + num::Num + PartialOrd> SkylinePacker<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// assert!(packer.insert(Size::new(10, 10)).is_some());
+    /// assert_eq!(None, packer.insert(Size::new(1, 1)));
+    /// ```
+    pub fn new(size: Size<T>) -> Self {
+        Self {
+            size,
+            allow_rotation: false,
+            skyline: vec![(T::zero(), T::zero(), size.width())],
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 4));
+    /// assert_eq!(None, packer.insert(Size::new(4, 10)));
+    /// let mut packer = SkylinePacker::new(Size::new(10, 4)).allow_rotation(true);
+    /// assert!(packer.insert(Size::new(4, 10)).unwrap().rotated());
+    /// ```
+    pub fn allow_rotation(self, allow_rotation: bool) -> Self {
+        Self {
+            allow_rotation,
+            ..self
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SkylinePacker;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(10, 4), SkylinePacker::new(Size::new(10, 4)).size());
+    /// ```
+    pub fn size(&self) -> Size<T> {
+        self.size
+    }
+
+    fn fit(&self, index: usize, size: Size<T>) -> Option<T> {
+        let (x, _, _) = self.skyline[index];
+        if self.size.width() < x + size.width() {
+            return None;
+        }
+        let mut y = T::zero();
+        let mut covered = T::zero();
+        for &(_, segment_y, segment_width) in &self.skyline[index..] {
+            if y < segment_y {
+                y = segment_y;
+            }
+            covered = covered + segment_width;
+            if !partial_lt(covered, size.width()) {
+                break;
+            }
+        }
+        if self.size.height() < y + size.height() {
+            None
+        } else {
+            Some(y)
+        }
+    }
+
+    fn place(&mut self, index: usize, rect: Rect<T>) {
+        let right = rect.right();
+        self.skyline
+            .insert(index, (rect.left(), rect.bottom(), rect.size().width()));
+        let next = index + 1;
+        while next < self.skyline.len() {
+            let (x, y, width) = self.skyline[next];
+            if !partial_lt(x, right) {
+                break;
+            }
+            let overlap = right - x;
+            if partial_lt(overlap, width) {
+                self.skyline[next] = (right, y, width - overlap);
+                break;
+            }
+            self.skyline.remove(next);
+        }
+        let mut i = 0;
+        while i + 1 < self.skyline.len() {
+            let (x, y, width) = self.skyline[i];
+            let (_, next_y, next_width) = self.skyline[i + 1];
+            if y == next_y {
+                self.skyline[i] = (x, y, width + next_width);
+                self.skyline.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl<T: Primitive + num::Num + PartialOrd> Packer<T> for SkylinePacker<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::SkylinePacker;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut packer = SkylinePacker::new(Size::new(10, 10));
+    /// assert_eq!(Some(Rect::new(Point::new(0, 0), Size::new(6, 4))), packer.insert(Size::new(6, 4)).map(|p| p.rect()));
+    /// assert_eq!(Some(Rect::new(Point::new(6, 0), Size::new(4, 8))), packer.insert(Size::new(4, 8)).map(|p| p.rect()));
+    /// assert_eq!(Some(Rect::new(Point::new(0, 4), Size::new(5, 5))), packer.insert(Size::new(5, 5)).map(|p| p.rect()));
+    /// assert_eq!(None, packer.insert(Size::new(5, 5)));
+    /// ```
+    fn insert(&mut self, size: Size<T>) -> Option<Placement<T>> {
+        let mut best: Option<(usize, Rect<T>, bool)> = None;
+        for (size, rotated) in orientations(size, self.allow_rotation) {
+            for index in 0..self.skyline.len() {
+                if let Some(y) = self.fit(index, size) {
+                    let (x, _, _) = self.skyline[index];
+                    let rect = Rect::new(Point::new(x, y), size);
+                    let better = best.is_none_or(|(_, best, _)| {
+                        partial_lt(rect.bottom(), best.bottom())
+                            || (rect.bottom() == best.bottom() && partial_lt(x, best.left()))
+                    });
+                    if better {
+                        best = Some((index, rect, rotated));
+                    }
+                }
+            }
+        }
+        let (index, rect, rotated) = best?;
+        self.place(index, rect);
+        Some(Placement { rect, rotated })
+    }
+}
+
+/// A packer which keeps every maximal free rect and places each item into the free rect it
+/// fits the most tightly on its shorter side.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MaxRectsPacker<T: Primitive> {
+    size: Size<T>,
+    allow_rotation: bool,
+    free: Vec<Rect<T>>,
+}
+
+impl<T: Primitive + num::Num + PartialOrd> MaxRectsPacker<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::MaxRectsPacker;
+    /// # use coord::Size;
+    /// let mut packer = MaxRectsPacker::new(Size::new(10, 10));
+    /// assert!(packer.insert(Size::new(10, 10)).is_some());
+    /// assert_eq!(None, packer.insert(Size::new(1, 1)));
+    /// ```
+    pub fn new(size: Size<T>) -> Self {
+        Self {
+            size,
+            allow_rotation: false,
+            free: vec![Rect::new(Point::new(T::zero(), T::zero()), size)],
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::MaxRectsPacker;
+    /// # use coord::Size;
+    /// let mut packer = MaxRectsPacker::new(Size::new(10, 4));
+    /// assert_eq!(None, packer.insert(Size::new(4, 10)));
+    /// let mut packer = MaxRectsPacker::new(Size::new(10, 4)).allow_rotation(true);
+    /// assert!(packer.insert(Size::new(4, 10)).unwrap().rotated());
+    /// ```
+    pub fn allow_rotation(self, allow_rotation: bool) -> Self {
+        Self {
+            allow_rotation,
+            ..self
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::MaxRectsPacker;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(10, 4), MaxRectsPacker::new(Size::new(10, 4)).size());
+    /// ```
+    pub fn size(&self) -> Size<T> {
+        self.size
+    }
+
+    fn split_free(&mut self, used: Rect<T>) {
+        let mut i = 0;
+        while i < self.free.len() {
+            let free = self.free[i];
+            if !free.intersects(used) {
+                i += 1;
+                continue;
+            }
+            self.free.swap_remove(i);
+            if partial_lt(free.left(), used.left()) {
+                self.free.push(Rect::new(
+                    free.origin(),
+                    Size::new(used.left() - free.left(), free.size().height()),
+                ));
+            }
+            if partial_lt(used.right(), free.right()) {
+                self.free.push(Rect::new(
+                    Point::new(used.right(), free.top()),
+                    Size::new(free.right() - used.right(), free.size().height()),
+                ));
+            }
+            if partial_lt(free.top(), used.top()) {
+                self.free.push(Rect::new(
+                    free.origin(),
+                    Size::new(free.size().width(), used.top() - free.top()),
+                ));
+            }
+            if partial_lt(used.bottom(), free.bottom()) {
+                self.free.push(Rect::new(
+                    Point::new(free.left(), used.bottom()),
+                    Size::new(free.size().width(), free.bottom() - used.bottom()),
+                ));
+            }
+        }
+        self.prune_free();
+    }
+
+    fn prune_free(&mut self) {
+        let mut i = 0;
+        while i < self.free.len() {
+            let contained = (0..self.free.len()).any(|j| {
+                j != i
+                    && self.free[j].contains_rect(self.free[i])
+                    && (self.free[j] != self.free[i] || j < i)
+            });
+            if contained {
+                self.free.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl<T: Primitive + num::Num + PartialOrd> Packer<T> for MaxRectsPacker<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::Packer;
+    /// # use coord::MaxRectsPacker;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut packer = MaxRectsPacker::new(Size::new(10, 10)).allow_rotation(true);
+    /// assert_eq!(Some(Rect::new(Point::new(0, 0), Size::new(10, 4))), packer.insert(Size::new(10, 4)).map(|p| p.rect()));
+    /// let placement = packer.insert(Size::new(6, 3)).unwrap();
+    /// assert_eq!(Rect::new(Point::new(0, 4), Size::new(3, 6)), placement.rect());
+    /// assert!(placement.rotated());
+    /// ```
+    fn insert(&mut self, size: Size<T>) -> Option<Placement<T>> {
+        let mut best: Option<((T, T), Rect<T>, bool)> = None;
+        for (size, rotated) in orientations(size, self.allow_rotation) {
+            for free in &self.free {
+                if partial_lt(free.size().width(), size.width())
+                    || partial_lt(free.size().height(), size.height())
+                {
+                    continue;
+                }
+                let leftover = (
+                    free.size().width() - size.width(),
+                    free.size().height() - size.height(),
+                );
+                let score = if partial_lt(leftover.0, leftover.1) {
+                    leftover
+                } else {
+                    (leftover.1, leftover.0)
+                };
+                let better = best
+                    .is_none_or(|(best, _, _)| score.partial_cmp(&best) == Some(Ordering::Less));
+                if better {
+                    best = Some((score, Rect::new(free.origin(), size), rotated));
+                }
+            }
+        }
+        let (_, rect, rotated) = best?;
+        self.split_free(rect);
+        Some(Placement { rect, rotated })
+    }
+}
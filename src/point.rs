@@ -1,16 +1,37 @@
 use crate::coord::{Coord, Primitive};
+use crate::unit::UnknownUnit;
 use num;
 use std::fmt;
+use std::marker::PhantomData;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Point<T: Primitive> {
+pub struct Point<T: Primitive, U = UnknownUnit> {
     x: T,
     y: T,
+    unit: PhantomData<U>,
 }
 
+impl_unit_traits!(Point { x, y });
+
 impl<T: Primitive> Point<T> {
     pub fn new(x: T, y: T) -> Self {
-        Self { x, y }
+        Self::typed(x, y)
+    }
+}
+
+impl<T: Primitive, U> Point<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// enum Screen {}
+    /// let p: Point<_, Screen> = Point::typed(4, 8);
+    /// assert_eq!(Point::new(4, 8), p.to_untyped());
+    /// ```
+    pub fn typed(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+            unit: PhantomData,
+        }
     }
 
     /// # Examples
@@ -40,7 +61,7 @@ impl<T: Primitive> Point<T> {
     where
         T: num::Zero,
     {
-        Self::new(T::zero(), T::zero())
+        Self::typed(T::zero(), T::zero())
     }
 
     /// # Examples
@@ -54,10 +75,38 @@ impl<T: Primitive> Point<T> {
     {
         self.x().is_zero() && self.y().is_zero()
     }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// enum Screen {}
+    /// enum World {}
+    /// let p: Point<_, Screen> = Point::typed(4, 8);
+    /// let q: Point<_, World> = p.cast_unit();
+    /// assert_eq!(Point::typed(4, 8), q);
+    /// ```
+    pub fn cast_unit<V>(self) -> Point<T, V> {
+        Point::typed(self.x(), self.y())
+    }
+
+    pub fn to_untyped(self) -> Point<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(point: Point<T>) -> Self {
+        point.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, U> Default for Point<T, U> {
+    fn default() -> Self {
+        Self::typed(T::default(), T::default())
+    }
 }
 
-impl<T: Primitive> Coord for Point<T> {
+impl<T: Primitive, U> Coord for Point<T, U> {
     type Item = T;
+    type Unit = U;
 
     /// # Examples
     /// ```
@@ -66,7 +115,7 @@ impl<T: Primitive> Coord for Point<T> {
     /// assert_eq!(Point::new(6, 12), Point::from_x_y(6, 12));
     /// ```
     fn from_x_y(x: T, y: T) -> Self {
-        Self::new(x, y)
+        Self::typed(x, y)
     }
 
     fn x(self) -> T {
@@ -78,7 +127,7 @@ impl<T: Primitive> Coord for Point<T> {
     }
 }
 
-impl<T: Primitive + fmt::Display> fmt::Display for Point<T> {
+impl<T: Primitive + fmt::Display, U> fmt::Display for Point<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.x(), self.y())
     }
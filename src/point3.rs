@@ -0,0 +1,142 @@
+use crate::coord::Primitive;
+use crate::coord3::Coord3;
+use crate::unit::UnknownUnit;
+use num;
+use std::fmt;
+use std::marker::PhantomData;
+
+pub struct Point3<T: Primitive, U = UnknownUnit> {
+    x: T,
+    y: T,
+    z: T,
+    unit: PhantomData<U>,
+}
+
+impl_unit_traits!(Point3 { x, y, z });
+
+impl<T: Primitive> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::typed(x, y, z)
+    }
+}
+
+impl<T: Primitive, U> Point3<T, U> {
+    pub fn typed(x: T, y: T, z: T) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// assert_eq!(4, Point3::new(4, 8, 2).x());
+    /// ```
+    pub fn x(self) -> T {
+        self.x
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// assert_eq!(8, Point3::new(4, 8, 2).y());
+    /// ```
+    pub fn y(self) -> T {
+        self.y
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// assert_eq!(2, Point3::new(4, 8, 2).z());
+    /// ```
+    pub fn z(self) -> T {
+        self.z
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// assert_eq!(Point3::new(0, 0, 0), Point3::zero());
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: num::Zero,
+    {
+        Self::typed(T::zero(), T::zero(), T::zero())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// assert!(Point3::new(0, 0, 0).is_zero());
+    /// assert!(!Point3::new(0, 0, 1).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool
+    where
+        T: num::Zero,
+    {
+        self.x().is_zero() && self.y().is_zero() && self.z().is_zero()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// enum Voxel {}
+    /// let p: Point3<_, Voxel> = Point3::new(4, 8, 2).cast_unit();
+    /// assert_eq!(Point3::typed(4, 8, 2), p);
+    /// ```
+    pub fn cast_unit<V>(self) -> Point3<T, V> {
+        Point3::typed(self.x(), self.y(), self.z())
+    }
+
+    pub fn to_untyped(self) -> Point3<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(point: Point3<T>) -> Self {
+        point.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, U> Default for Point3<T, U> {
+    fn default() -> Self {
+        Self::typed(T::default(), T::default(), T::default())
+    }
+}
+
+impl<T: Primitive, U> Coord3 for Point3<T, U> {
+    type Item = T;
+    type Unit = U;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point3;
+    /// # use coord::Coord3;
+    /// assert_eq!(Point3::new(6, 12, 18), Point3::from_x_y_z(6, 12, 18));
+    /// ```
+    fn from_x_y_z(x: T, y: T, z: T) -> Self {
+        Self::typed(x, y, z)
+    }
+
+    fn x(self) -> T {
+        self.x()
+    }
+
+    fn y(self) -> T {
+        self.y()
+    }
+
+    fn z(self) -> T {
+        self.z()
+    }
+}
+
+impl<T: Primitive + fmt::Display, U> fmt::Display for Point3<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x(), self.y(), self.z())
+    }
+}
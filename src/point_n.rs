@@ -0,0 +1,154 @@
+use crate::coord::Primitive;
+use crate::coord_n::CoordN;
+use crate::point::Point;
+use crate::unit::UnknownUnit;
+use num;
+use std::array;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+/// A point of `N` components, which are indexed from 0 in the order x, y, z, and so on.
+pub struct PointN<T: Primitive, const N: usize, U = UnknownUnit> {
+    components: [T; N],
+    unit: PhantomData<U>,
+}
+
+impl_unit_traits!(PointN<const N, U> { components });
+
+impl<T: Primitive, const N: usize> PointN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self::typed(components)
+    }
+}
+
+impl<T: Primitive, const N: usize, U> PointN<T, N, U> {
+    pub fn typed(components: [T; N]) -> Self {
+        Self {
+            components,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// assert_eq!([4, 8, 2, 6], PointN::new([4, 8, 2, 6]).components());
+    /// ```
+    pub fn components(self) -> [T; N] {
+        self.components
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// assert_eq!(PointN::new([0, 0, 0, 0]), PointN::zero());
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: num::Zero,
+    {
+        Self::typed(array::from_fn(|_| T::zero()))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// assert!(PointN::new([0, 0, 0, 0]).is_zero());
+    /// assert!(!PointN::new([0, 0, 1, 0]).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool
+    where
+        T: num::Zero,
+    {
+        self.components.iter().all(|n| n.is_zero())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// enum Voxel {}
+    /// let p: PointN<_, 3, Voxel> = PointN::new([1, 2, 3]).cast_unit();
+    /// assert_eq!(PointN::typed([1, 2, 3]), p);
+    /// ```
+    pub fn cast_unit<V>(self) -> PointN<T, N, V> {
+        PointN::typed(self.components)
+    }
+
+    pub fn to_untyped(self) -> PointN<T, N> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(point: PointN<T, N>) -> Self {
+        point.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, const N: usize, U> Default for PointN<T, N, U> {
+    fn default() -> Self {
+        Self::typed(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Primitive, const N: usize, U> CoordN<N> for PointN<T, N, U> {
+    type Item = T;
+    type Unit = U;
+
+    fn from_array(components: [T; N]) -> Self {
+        Self::typed(components)
+    }
+
+    fn to_array(self) -> [T; N] {
+        self.components()
+    }
+}
+
+impl<T: Primitive, const N: usize, U> Index<usize> for PointN<T, N, U> {
+    type Output = T;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// assert_eq!(2, PointN::new([4, 8, 2, 6])[2]);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<T: Primitive, const N: usize, U> IndexMut<usize> for PointN<T, N, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// let mut p = PointN::new([4, 8, 2, 6]);
+    /// p[3] = 10;
+    /// assert_eq!(PointN::new([4, 8, 2, 10]), p);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.components[index]
+    }
+}
+
+impl<T: Primitive, U> From<Point<T, U>> for PointN<T, 2, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::Point;
+    /// assert_eq!(PointN::new([3, 4]), PointN::from(Point::new(3, 4)));
+    /// ```
+    fn from(point: Point<T, U>) -> Self {
+        Self::typed([point.x(), point.y()])
+    }
+}
+
+impl<T: Primitive, U> From<PointN<T, 2, U>> for Point<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::PointN;
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(3, 4), Point::from(PointN::new([3, 4])));
+    /// ```
+    fn from(point: PointN<T, 2, U>) -> Self {
+        let [x, y] = point.components();
+        Point::typed(x, y)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::coord::Primitive;
+use crate::point_n::PointN;
+use crate::point_range_iterator::PointStep;
+use crate::unit::UnknownUnit;
+use crate::vector_n::VectorN;
+
+fn next<S: Primitive, T: PointStep<S>, const N: usize, U>(
+    start: PointN<T, N, U>,
+    end: PointN<T, N, U>,
+    step: VectorN<S, N, U>,
+    current: &mut PointN<T, N, U>,
+    compare: fn(T, T) -> bool,
+) -> Option<PointN<T, N, U>> {
+    if N == 0 || !(0..N).all(|i| compare(start[i], end[i])) {
+        return None;
+    }
+    loop {
+        for i in 0..N - 1 {
+            if compare(current[i], end[i]) {
+                break;
+            }
+            current[i] = start[i];
+            current[i + 1] = current[i + 1] + step[i + 1];
+        }
+        if !compare(current[N - 1], end[N - 1]) {
+            return None;
+        }
+        if (0..N).all(|i| compare(current[i], end[i])) {
+            let result = *current;
+            current[0] = current[0] + step[0];
+            return Some(result);
+        }
+    }
+}
+
+/// Iterates the points from `start` to `end` exclusive by `step`, running through the first
+/// component fastest like `PointRangeIterator` does with x.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PointNRangeIterator<T: Primitive, S: Primitive, const N: usize, U = UnknownUnit> {
+    start: PointN<T, N, U>,
+    end: PointN<T, N, U>,
+    step: VectorN<S, N, U>,
+    current: PointN<T, N, U>,
+}
+
+impl<T: Primitive, S: Primitive, const N: usize, U> PointNRangeIterator<T, S, N, U> {
+    pub fn new(start: PointN<T, N, U>, end: PointN<T, N, U>, step: VectorN<S, N, U>) -> Self {
+        Self {
+            start,
+            end,
+            step,
+            current: start,
+        }
+    }
+}
+
+impl<S: Primitive, T: PointStep<S>, const N: usize, U> Iterator
+    for PointNRangeIterator<T, S, N, U>
+{
+    type Item = PointN<T, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointNRangeIterator;
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// assert_eq!(
+    ///     vec![
+    ///         PointN::new([0, 0, 0]), PointN::new([2, 0, 0]),
+    ///         PointN::new([0, 1, 0]), PointN::new([2, 1, 0]),
+    ///         PointN::new([0, 0, 1]), PointN::new([2, 0, 1]),
+    ///         PointN::new([0, 1, 1]), PointN::new([2, 1, 1]),
+    ///     ],
+    ///     PointNRangeIterator::new(PointN::new([0, 0, 0]), PointN::new([3, 2, 2]), VectorN::new([2, 1, 1]))
+    ///         .collect::<Vec<_>>());
+    /// ```
+    fn next(&mut self) -> Option<PointN<T, N, U>> {
+        next(
+            self.start,
+            self.end,
+            self.step,
+            &mut self.current,
+            |n, m| n < m,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PointNRangeIteratorInclusive<T: Primitive, S: Primitive, const N: usize, U = UnknownUnit>
+{
+    start: PointN<T, N, U>,
+    end: PointN<T, N, U>,
+    step: VectorN<S, N, U>,
+    current: PointN<T, N, U>,
+}
+
+impl<T: Primitive, S: Primitive, const N: usize, U> PointNRangeIteratorInclusive<T, S, N, U> {
+    pub fn new(start: PointN<T, N, U>, end: PointN<T, N, U>, step: VectorN<S, N, U>) -> Self {
+        Self {
+            start,
+            end,
+            step,
+            current: start,
+        }
+    }
+}
+
+impl<S: Primitive, T: PointStep<S>, const N: usize, U> Iterator
+    for PointNRangeIteratorInclusive<T, S, N, U>
+{
+    type Item = PointN<T, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::PointNRangeIteratorInclusive;
+    /// # use coord::PointN;
+    /// # use coord::VectorN;
+    /// assert_eq!(
+    ///     vec![
+    ///         PointN::new([0, 5]), PointN::new([1, 5]),
+    ///         PointN::new([0, 6]), PointN::new([1, 6]),
+    ///     ],
+    ///     PointNRangeIteratorInclusive::new(PointN::new([0, 5]), PointN::new([1, 6]), VectorN::new([1, 1]))
+    ///         .collect::<Vec<_>>());
+    /// ```
+    fn next(&mut self) -> Option<PointN<T, N, U>> {
+        next(
+            self.start,
+            self.end,
+            self.step,
+            &mut self.current,
+            |n, m| n <= m,
+        )
+    }
+}
@@ -1,17 +1,19 @@
 use crate::coord::Primitive;
 use crate::point::Point;
 use crate::point_range_iterator::{PointRangeIterator, PointRangeIteratorInclusive, PointStep};
+use crate::unit::UnknownUnit;
 use crate::vector::Vector;
 use std::ops;
 
-pub trait PointRangeBounds<T: Primitive> {
-    fn contains<U: Primitive>(&self, point: Point<U>) -> bool
+pub trait PointRangeBounds<T: Primitive, U = UnknownUnit> {
+    #[allow(clippy::multiple_bound_locations)]
+    fn contains<V: Primitive>(&self, point: Point<V, U>) -> bool
     where
-        T: PartialOrd<U>,
-        U: PartialOrd<T>;
+        T: PartialOrd<V>,
+        V: PartialOrd<T>;
 }
 
-impl<T: Primitive, B: ops::RangeBounds<Point<T>>> PointRangeBounds<T> for B {
+impl<T: Primitive, U, B: ops::RangeBounds<Point<T, U>>> PointRangeBounds<T, U> for B {
     /// # Examples
     /// ```
     /// # use coord::PointRangeBounds;
@@ -23,10 +25,11 @@ impl<T: Primitive, B: ops::RangeBounds<Point<T>>> PointRangeBounds<T> for B {
     /// assert!((..=Point::new(10, 20)).contains(Point::new(10, 20)));
     /// assert!((..).contains(Point::new(100, 200)));
     /// ```
-    fn contains<U: Primitive>(&self, point: Point<U>) -> bool
+    #[allow(clippy::multiple_bound_locations)]
+    fn contains<V: Primitive>(&self, point: Point<V, U>) -> bool
     where
-        T: PartialOrd<U>,
-        U: PartialOrd<T>,
+        T: PartialOrd<V>,
+        V: PartialOrd<T>,
     {
         use ops::Bound::*;
         (match self.start_bound() {
@@ -41,14 +44,14 @@ impl<T: Primitive, B: ops::RangeBounds<Point<T>>> PointRangeBounds<T> for B {
     }
 }
 
-pub trait PointRange<T: Primitive> {
-    type PointIterator: Iterator<Item = Point<T>>;
+pub trait PointRange<T: Primitive, U = UnknownUnit> {
+    type PointIterator: Iterator<Item = Point<T, U>>;
 
     fn points(&self) -> Self::PointIterator;
 }
 
-impl<T: PointStep> PointRange<T> for ops::Range<Point<T>> {
-    type PointIterator = PointRangeIterator<T, T>;
+impl<T: PointStep, U> PointRange<T, U> for ops::Range<Point<T, U>> {
+    type PointIterator = PointRangeIterator<T, T, U>;
 
     /// # Examples
     /// ```
@@ -64,12 +67,12 @@ impl<T: PointStep> PointRange<T> for ops::Range<Point<T>> {
     ///     (Point::new(10, 20)..Point::new(13, 24)).points().collect::<Vec<_>>());
     /// ```
     fn points(&self) -> Self::PointIterator {
-        PointRangeIterator::new(self.start, self.end, Vector::new(T::one(), T::one()))
+        PointRangeIterator::new(self.start, self.end, Vector::typed(T::one(), T::one()))
     }
 }
 
-impl<T: PointStep> PointRange<T> for ops::RangeInclusive<Point<T>> {
-    type PointIterator = PointRangeIteratorInclusive<T, T>;
+impl<T: PointStep, U> PointRange<T, U> for ops::RangeInclusive<Point<T, U>> {
+    type PointIterator = PointRangeIteratorInclusive<T, T, U>;
 
     /// # Examples
     /// ```
@@ -89,7 +92,7 @@ impl<T: PointStep> PointRange<T> for ops::RangeInclusive<Point<T>> {
         PointRangeIteratorInclusive::new(
             *self.start(),
             *self.end(),
-            Vector::new(T::one(), T::one()),
+            Vector::typed(T::one(), T::one()),
         )
     }
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use crate::axis::Axis;
 use crate::axis::AxisKeyed;
 use crate::coord::Primitive;
 use crate::point::Point;
+use crate::unit::UnknownUnit;
 use crate::vector::Vector;
 use std::ops;
 
@@ -15,34 +16,34 @@ impl<S: Primitive, T: Primitive + PartialOrd + ops::Add<S, Output = Self> + num:
 {
 }
 
-fn next<S: Primitive, T: PointStep<S>>(
-    start: Point<T>,
-    end: Point<T>,
-    step: Vector<S>,
-    current: &mut Point<T>,
+fn next<S: Primitive, T: PointStep<S>, U>(
+    start: Point<T, U>,
+    end: Point<T, U>,
+    step: Vector<S, U>,
+    current: &mut Point<T, U>,
     compare: fn(T, T) -> bool,
-) -> Option<Point<T>> {
+) -> Option<Point<T, U>> {
     while compare(current.y(), end.y()) {
         if compare(current.x(), end.x()) {
             let result = Some(*current);
             *current = current.add(Axis::X, step.x());
             return result;
         }
-        *current = Point::new(start.x(), current.y() + step.y());
+        *current = Point::typed(start.x(), current.y() + step.y());
     }
     None
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PointRangeIterator<T: Primitive, S: Primitive> {
-    start: Point<T>,
-    end: Point<T>,
-    step: Vector<S>,
-    current: Point<T>,
+pub struct PointRangeIterator<T: Primitive, S: Primitive, U = UnknownUnit> {
+    start: Point<T, U>,
+    end: Point<T, U>,
+    step: Vector<S, U>,
+    current: Point<T, U>,
 }
 
-impl<T: Primitive, S: Primitive> PointRangeIterator<T, S> {
-    pub fn new(start: Point<T>, end: Point<T>, step: Vector<S>) -> Self {
+impl<T: Primitive, S: Primitive, U> PointRangeIterator<T, S, U> {
+    pub fn new(start: Point<T, U>, end: Point<T, U>, step: Vector<S, U>) -> Self {
         Self {
             start,
             end,
@@ -52,10 +53,10 @@ impl<T: Primitive, S: Primitive> PointRangeIterator<T, S> {
     }
 }
 
-impl<S: Primitive, T: PointStep<S>> Iterator for PointRangeIterator<T, S> {
-    type Item = Point<T>;
+impl<S: Primitive, T: PointStep<S>, U> Iterator for PointRangeIterator<T, S, U> {
+    type Item = Point<T, U>;
 
-    fn next(&mut self) -> Option<Point<T>> {
+    fn next(&mut self) -> Option<Point<T, U>> {
         next(
             self.start,
             self.end,
@@ -67,15 +68,15 @@ impl<S: Primitive, T: PointStep<S>> Iterator for PointRangeIterator<T, S> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PointRangeIteratorInclusive<T: Primitive, S: Primitive> {
-    start: Point<T>,
-    end: Point<T>,
-    step: Vector<S>,
-    current: Point<T>,
+pub struct PointRangeIteratorInclusive<T: Primitive, S: Primitive, U = UnknownUnit> {
+    start: Point<T, U>,
+    end: Point<T, U>,
+    step: Vector<S, U>,
+    current: Point<T, U>,
 }
 
-impl<T: Primitive, S: Primitive> PointRangeIteratorInclusive<T, S> {
-    pub fn new(start: Point<T>, end: Point<T>, step: Vector<S>) -> Self {
+impl<T: Primitive, S: Primitive, U> PointRangeIteratorInclusive<T, S, U> {
+    pub fn new(start: Point<T, U>, end: Point<T, U>, step: Vector<S, U>) -> Self {
         Self {
             start,
             end,
@@ -85,10 +86,10 @@ impl<T: Primitive, S: Primitive> PointRangeIteratorInclusive<T, S> {
     }
 }
 
-impl<S: Primitive, T: PointStep<S>> Iterator for PointRangeIteratorInclusive<T, S> {
-    type Item = Point<T>;
+impl<S: Primitive, T: PointStep<S>, U> Iterator for PointRangeIteratorInclusive<T, S, U> {
+    type Item = Point<T, U>;
 
-    fn next(&mut self) -> Option<Point<T>> {
+    fn next(&mut self) -> Option<Point<T, U>> {
         next(
             self.start,
             self.end,
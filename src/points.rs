@@ -7,7 +7,7 @@ use crate::point_range_iterator::{PointRangeIterator, PointRangeIteratorInclusiv
 use crate::point_range::PointRange;
 use std::ops;
 
-impl<T: Primitive> Size<T> where Point<T>: ops::Add<Self, Output = Point<T>> {
+impl<T: Primitive, U> Size<T, U> where Point<T, U>: ops::Add<Self, Output = Point<T, U>> {
     /// # Examples
     /// ```
     /// # use coord::Size;
@@ -20,7 +20,7 @@ impl<T: Primitive> Size<T> where Point<T>: ops::Add<Self, Output = Point<T>> {
     ///     ],
     ///     Size::new(6, 2).points_step(Point::new(3, 2), Vector::new(2, 1)).collect::<Vec<_>>());
     /// ```
-    pub fn points_step<S: Primitive>(&self, origin: Point<T>, step: Vector<S>) -> impl Iterator<Item = Point<T>> where T: PointStep<S> {
+    pub fn points_step<S: Primitive>(&self, origin: Point<T, U>, step: Vector<S, U>) -> impl Iterator<Item = Point<T, U>> where T: PointStep<S> {
         PointRangeIterator::new(origin, origin + *self, step)
     }
 
@@ -35,7 +35,7 @@ impl<T: Primitive> Size<T> where Point<T>: ops::Add<Self, Output = Point<T>> {
     ///     ],
     ///     Size::new(3, 2).points(Point::new(3, 2)).collect::<Vec<_>>());
     /// ```
-    pub fn points(&self, origin: Point<T>) -> impl Iterator<Item = Point<T>> where T: PointStep {
+    pub fn points(&self, origin: Point<T, U>) -> impl Iterator<Item = Point<T, U>> where T: PointStep {
         (origin..(origin + *self)).points()
     }
 
@@ -54,9 +54,9 @@ impl<T: Primitive> Size<T> where Point<T>: ops::Add<Self, Output = Point<T>> {
     /// ```
     pub fn points_step_inclusive<S: Primitive>(
         &self,
-        origin: Point<T>,
-        step: Vector<S>,
-    ) -> impl Iterator<Item = Point<T>> where T: PointStep<S> {
+        origin: Point<T, U>,
+        step: Vector<S, U>,
+    ) -> impl Iterator<Item = Point<T, U>> where T: PointStep<S> {
         PointRangeIteratorInclusive::new(origin, origin + *self, step)
     }
 
@@ -72,12 +72,12 @@ impl<T: Primitive> Size<T> where Point<T>: ops::Add<Self, Output = Point<T>> {
     ///     ],
     ///     Size::new(3, 2).points_inclusive(Point::new(3, 2)).collect::<Vec<_>>());
     /// ```
-    pub fn points_inclusive(&self, origin: Point<T>) -> impl Iterator<Item = Point<T>> where T: PointStep {
+    pub fn points_inclusive(&self, origin: Point<T, U>) -> impl Iterator<Item = Point<T, U>> where T: PointStep {
         (origin..=(origin + *self)).points()
     }
 }
 
-impl<T: Primitive> Rect<T> {
+impl<T: Primitive, U> Rect<T, U> {
     /// # Examples
     /// ```
     /// # use coord::Rect;
@@ -91,7 +91,7 @@ impl<T: Primitive> Rect<T> {
     ///     ],
     ///     Rect::new(Point::new(10, 20), Size::new(2, 3)).points().collect::<Vec<_>>());
     /// ```
-    pub fn points(&self) -> impl Iterator<Item = Point<T>> where T: PointStep {
+    pub fn points(&self) -> impl Iterator<Item = Point<T, U>> where T: PointStep {
         self.size().points(self.origin())
     }
 }
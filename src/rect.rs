@@ -1,20 +1,103 @@
+use crate::bounding_box::BoundingBox;
+use crate::component_wise::ComponentWise;
 use crate::coord::{Coord, Primitive};
+use crate::map::Map;
 use crate::point::Point;
+use crate::side_offsets::SideOffsets;
 use crate::size::Size;
+use crate::unit::UnknownUnit;
+use crate::vector::Vector;
+use num;
+use std::convert::TryFrom;
 use std::fmt;
-use std::ops::Add;
+use std::ops::{Add, Range, RangeInclusive, Sub};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Rect<T: Primitive> {
-    origin: Point<T>,
-    size: Size<T>,
+pub struct Rect<T: Primitive, U = UnknownUnit> {
+    origin: Point<T, U>,
+    size: Size<T, U>,
+}
+
+impl_unit_traits!(Rect { origin, size });
+
+fn saturating_sub<T: Primitive + Sub<T, Output = T> + PartialOrd + num::Zero>(n: T, m: T) -> T {
+    if m < n {
+        n - m
+    } else {
+        T::zero()
+    }
 }
 
 impl<T: Primitive> Rect<T> {
     pub fn new(origin: Point<T>, size: Size<T>) -> Self {
+        Self::typed(origin, size)
+    }
+}
+
+impl<T: Primitive, U> Rect<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// enum Screen {}
+    /// let r: Rect<_, Screen> = Rect::typed(Point::typed(1, 2), Size::typed(3, 4));
+    /// assert_eq!(Rect::new(Point::new(1, 2), Size::new(3, 4)), r.to_untyped());
+    /// ```
+    pub fn typed(origin: Point<T, U>, size: Size<T, U>) -> Self {
         Self { origin, size }
     }
 
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(2, 3), Size::new(8, 4)),
+    ///     Rect::from_corners(Point::new(10, 3), Point::new(2, 7)));
+    /// ```
+    pub fn from_corners(a: Point<T, U>, b: Point<T, U>) -> Self
+    where
+        T: PartialOrd + Sub<T, Output = T>,
+    {
+        let (min, max) = (a.component_min(b), a.component_max(b));
+        Self::typed(min, (max, min).map(|(n, m)| n - m))
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(8, 16), Size::new(4, 8)),
+    ///     Rect::from_center(Point::new(10, 20), Size::new(4, 8)));
+    /// ```
+    pub fn from_center(center: Point<T, U>, size: Size<T, U>) -> Self
+    where
+        T: num::Num,
+    {
+        let two = T::one() + T::one();
+        Self::typed((center, size).map(|(c, s)| c - s / two), size)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Some(Rect::new(Point::new(1, 2), Size::new(9, 6))),
+    ///     Rect::from_points(vec![Point::new(5, 8), Point::new(1, 4), Point::new(10, 2)]));
+    /// assert_eq!(None, Rect::<i32>::from_points(vec![]));
+    /// ```
+    pub fn from_points(points: impl IntoIterator<Item = Point<T, U>>) -> Option<Self>
+    where
+        T: PartialOrd + Sub<T, Output = T>,
+    {
+        points.into_iter().collect::<BoundingBox<T, U>>().rect()
+    }
+
     /// # Examples
     /// ```
     /// # use coord::Rect;
@@ -22,7 +105,7 @@ impl<T: Primitive> Rect<T> {
     /// # use coord::Size;
     /// assert_eq!(Point::new(4, 5), Rect::new(Point::new(4, 5), Size::new(10, 20)).origin());
     /// ```
-    pub fn origin(self) -> Point<T> {
+    pub fn origin(self) -> Point<T, U> {
         self.origin
     }
 
@@ -33,10 +116,21 @@ impl<T: Primitive> Rect<T> {
     /// # use coord::Size;
     /// assert_eq!(Size::new(10, 20), Rect::new(Point::new(4, 5), Size::new(10, 20)).size());
     /// ```
-    pub fn size(self) -> Size<T> {
+    pub fn size(self) -> Size<T, U> {
         self.size
     }
 
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(3, 4), Rect::new(Point::new(3, 4), Size::new(10, 20)).min());
+    /// ```
+    pub fn min(self) -> Point<T, U> {
+        self.origin()
+    }
+
     /// # Examples
     /// ```
     /// # use coord::Rect;
@@ -44,13 +138,232 @@ impl<T: Primitive> Rect<T> {
     /// # use coord::Size;
     /// assert_eq!(Point::new(13, 24), Rect::new(Point::new(3, 4), Size::new(10, 20)).max());
     /// ```
-    pub fn max(self) -> Point<T>
+    pub fn max(self) -> Point<T, U>
     where
-        Point<T>: Add<Size<T>, Output = Point<T>>,
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
     {
         self.origin() + self.size()
     }
 
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(8, 14), Rect::new(Point::new(3, 4), Size::new(10, 20)).center());
+    /// ```
+    pub fn center(self) -> Point<T, U>
+    where
+        T: num::Num,
+    {
+        let two = T::one() + T::one();
+        (self.origin(), self.size()).map(|(o, s)| o + s / two)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(3, Rect::new(Point::new(3, 4), Size::new(10, 20)).left());
+    /// ```
+    pub fn left(self) -> T {
+        self.origin().x()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(13, Rect::new(Point::new(3, 4), Size::new(10, 20)).right());
+    /// ```
+    pub fn right(self) -> T
+    where
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        self.max().x()
+    }
+
+    /// Returns the minimum y, which is the top edge in the y-down convention of this crate.
+    /// Use `YDirection` for the visually upper edge in y-up coordinates.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(4, Rect::new(Point::new(3, 4), Size::new(10, 20)).top());
+    /// ```
+    pub fn top(self) -> T {
+        self.origin().y()
+    }
+
+    /// Returns the maximum y, which is the bottom edge in the y-down convention of this crate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(24, Rect::new(Point::new(3, 4), Size::new(10, 20)).bottom());
+    /// ```
+    pub fn bottom(self) -> T
+    where
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        self.max().y()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(3, 4), Rect::new(Point::new(3, 4), Size::new(10, 20)).top_left());
+    /// ```
+    pub fn top_left(self) -> Point<T, U> {
+        self.origin()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(13, 4), Rect::new(Point::new(3, 4), Size::new(10, 20)).top_right());
+    /// ```
+    pub fn top_right(self) -> Point<T, U>
+    where
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        Point::typed(self.right(), self.top())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(3, 24), Rect::new(Point::new(3, 4), Size::new(10, 20)).bottom_left());
+    /// ```
+    pub fn bottom_left(self) -> Point<T, U>
+    where
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        Point::typed(self.left(), self.bottom())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(Point::new(13, 24), Rect::new(Point::new(3, 4), Size::new(10, 20)).bottom_right());
+    /// ```
+    pub fn bottom_right(self) -> Point<T, U>
+    where
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        self.max()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Vector;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(8, 17), Size::new(14, 26)),
+    ///     Rect::new(Point::new(10, 20), Size::new(10, 20)).inflate(Vector::new(2, 3)));
+    /// ```
+    pub fn inflate(self, vector: Vector<T, U>) -> Self
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        Self::typed(
+            self.origin() - vector,
+            (self.size(), vector).map(|(s, v)| s + v + v),
+        )
+    }
+
+    /// The size is clamped at zero when the rect is smaller than the margins.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Vector;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(12, 23), Size::new(6, 14)),
+    ///     Rect::new(Point::new(10, 20), Size::new(10, 20)).deflate(Vector::new(2, 3)));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(12u32, 23), Size::new(0, 0)),
+    ///     Rect::new(Point::new(10u32, 20), Size::new(3, 5)).deflate(Vector::new(2, 3)));
+    /// ```
+    pub fn deflate(self, vector: Vector<T, U>) -> Self
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + PartialOrd + num::Zero,
+    {
+        Self::typed(
+            self.origin() + vector,
+            (self.size(), vector).map(|(s, v)| saturating_sub(s, v + v)),
+        )
+    }
+
+    /// The size is clamped at zero when the rect is smaller than the offsets.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(14, 21), Size::new(94, 46)),
+    ///     Rect::new(Point::new(10, 20), Size::new(100, 50)).inset(SideOffsets::new(1, 2, 3, 4)));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(4u32, 1), Size::new(0, 0)),
+    ///     Rect::new(Point::new(0u32, 0), Size::new(5, 3)).inset(SideOffsets::new(1, 2, 3, 4)));
+    /// ```
+    pub fn inset(self, offsets: SideOffsets<T>) -> Self
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T> + PartialOrd + num::Zero,
+    {
+        Self::typed(
+            Point::typed(self.left() + offsets.left(), self.top() + offsets.top()),
+            Size::typed(
+                saturating_sub(self.size().width(), offsets.horizontal()),
+                saturating_sub(self.size().height(), offsets.vertical()),
+            ),
+        )
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::SideOffsets;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(6, 19), Size::new(106, 54)),
+    ///     Rect::new(Point::new(10, 20), Size::new(100, 50)).outset(SideOffsets::new(1, 2, 3, 4)));
+    /// ```
+    pub fn outset(self, offsets: SideOffsets<T>) -> Self
+    where
+        T: Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        Self::typed(
+            Point::typed(self.left() - offsets.left(), self.top() - offsets.top()),
+            Size::typed(
+                self.size().width() + offsets.horizontal(),
+                self.size().height() + offsets.vertical(),
+            ),
+        )
+    }
+
     /// # Examples
     /// ```
     /// # use coord::Rect;
@@ -58,20 +371,114 @@ impl<T: Primitive> Rect<T> {
     /// # use coord::Size;
     /// assert!(Rect::new(Point::new(5, 10), Size::new(4, 8)).contains(Point::new(7, 13)))
     /// ```
-    pub fn contains(&self, point: Point<T>) -> bool
+    pub fn contains(&self, point: Point<T, U>) -> bool
     where
         T: PartialOrd,
-        Point<T>: Add<Size<T>, Output = Point<T>>,
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
     {
         self.origin.x() <= point.x()
             && self.origin.y() <= point.y()
             && point.x() < self.max().x()
             && point.y() < self.max().y()
     }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// assert!(rect.contains_rect(Rect::new(Point::new(2, 3), Size::new(8, 7))));
+    /// assert!(!rect.contains_rect(Rect::new(Point::new(2, 3), Size::new(9, 7))));
+    /// ```
+    pub fn contains_rect(&self, other: Rect<T, U>) -> bool
+    where
+        T: PartialOrd,
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        self.left() <= other.left()
+            && self.top() <= other.top()
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// assert!(rect.intersects(Rect::new(Point::new(9, 9), Size::new(5, 5))));
+    /// assert!(!rect.intersects(Rect::new(Point::new(10, 0), Size::new(5, 5))));
+    /// ```
+    pub fn intersects(&self, other: Rect<T, U>) -> bool
+    where
+        T: PartialOrd,
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        self.left() < other.right()
+            && other.left() < self.right()
+            && self.top() < other.bottom()
+            && other.top() < self.bottom()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(0, 0), Size::new(10, 10));
+    /// assert_eq!(
+    ///     Some(Rect::new(Point::new(5, 8), Size::new(5, 2))),
+    ///     rect.intersection(Rect::new(Point::new(5, 8), Size::new(10, 10))));
+    /// assert_eq!(None, rect.intersection(Rect::new(Point::new(0, 10), Size::new(10, 10))));
+    /// ```
+    pub fn intersection(&self, other: Rect<T, U>) -> Option<Self>
+    where
+        T: PartialOrd + Sub<T, Output = T>,
+        Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+    {
+        if self.intersects(other) {
+            Some(Self::from_corners(
+                self.min().component_max(other.min()),
+                self.max().component_min(other.max()),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// enum Screen {}
+    /// let r: Rect<_, Screen> = Rect::new(Point::new(1, 2), Size::new(3, 4)).cast_unit();
+    /// assert_eq!(Rect::typed(Point::typed(1, 2), Size::typed(3, 4)), r);
+    /// ```
+    pub fn cast_unit<V>(self) -> Rect<T, V> {
+        Rect::typed(self.origin().cast_unit(), self.size().cast_unit())
+    }
+
+    pub fn to_untyped(self) -> Rect<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(rect: Rect<T>) -> Self {
+        rect.cast_unit()
+    }
 }
 
-impl<T: Primitive> Coord for Rect<T> {
+impl<T: Primitive + Default, U> Default for Rect<T, U> {
+    fn default() -> Self {
+        Self::typed(Point::default(), Size::default())
+    }
+}
+
+impl<T: Primitive, U> Coord for Rect<T, U> {
     type Item = (T, T);
+    type Unit = U;
 
     /// # Examples
     /// ```
@@ -82,7 +489,10 @@ impl<T: Primitive> Coord for Rect<T> {
     /// assert_eq!(Rect::new(Point::new(3, 4), Size::new(10, 20)), Rect::from_x_y((3, 10), (4, 20)));
     /// ```
     fn from_x_y((x_origin, x_size): (T, T), (y_origin, y_size): (T, T)) -> Self {
-        Self::new(Point::new(x_origin, y_origin), Size::new(x_size, y_size))
+        Self::typed(
+            Point::typed(x_origin, y_origin),
+            Size::typed(x_size, y_size),
+        )
     }
 
     /// # Examples
@@ -110,8 +520,97 @@ impl<T: Primitive> Coord for Rect<T> {
     }
 }
 
-impl<T: Primitive + fmt::Display> fmt::Display for Rect<T> {
+impl<T: Primitive + fmt::Display, U> fmt::Display for Rect<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.origin(), self.size())
     }
 }
+
+impl<T, U> From<Range<Point<T, U>>> for Rect<T, U>
+where
+    T: Primitive + Sub<T, Output = T> + PartialOrd + num::Zero,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(3, 4), Size::new(10, 20)),
+    ///     Rect::from(Point::new(3, 4)..Point::new(13, 24)));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(3u32, 4), Size::new(0, 20)),
+    ///     Rect::from(Point::new(3u32, 4)..Point::new(1, 24)));
+    /// ```
+    fn from(range: Range<Point<T, U>>) -> Self {
+        let size = (range.end, range.start).map(|(e, s)| saturating_sub(e, s));
+        Self::typed(range.start, size)
+    }
+}
+
+impl<T: Primitive, U> From<Rect<T, U>> for Range<Point<T, U>>
+where
+    Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use std::ops::Range;
+    /// assert_eq!(
+    ///     Point::new(3, 4)..Point::new(13, 24),
+    ///     Range::from(Rect::new(Point::new(3, 4), Size::new(10, 20))));
+    /// ```
+    fn from(rect: Rect<T, U>) -> Self {
+        rect.origin()..rect.max()
+    }
+}
+
+impl<T: Primitive + num::Integer, U> From<RangeInclusive<Point<T, U>>> for Rect<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(3, 4), Size::new(10, 20)),
+    ///     Rect::from(Point::new(3, 4)..=Point::new(12, 23)));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(3u32, 4), Size::new(0, 20)),
+    ///     Rect::from(Point::new(3u32, 4)..=Point::new(1, 23)));
+    /// ```
+    fn from(range: RangeInclusive<Point<T, U>>) -> Self {
+        let (start, end) = range.into_inner();
+        let length = |e: T, s: T| if s <= e { e - s + T::one() } else { T::zero() };
+        Self::typed(start, (end, start).map(|(e, s)| length(e, s)))
+    }
+}
+
+impl<T: Primitive + num::Integer, U> TryFrom<Rect<T, U>> for RangeInclusive<Point<T, U>>
+where
+    Point<T, U>: Add<Size<T, U>, Output = Point<T, U>>,
+{
+    /// The empty rect, which no inclusive range of points can cover.
+    type Error = Rect<T, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use std::convert::TryFrom;
+    /// # use std::ops::RangeInclusive;
+    /// assert_eq!(
+    ///     Ok(Point::new(3, 4)..=Point::new(12, 23)),
+    ///     RangeInclusive::try_from(Rect::new(Point::new(3, 4), Size::new(10, 20))));
+    /// let empty = Rect::new(Point::new(0u32, 0), Size::new(0, 20));
+    /// assert_eq!(Err(empty), RangeInclusive::try_from(empty));
+    /// ```
+    fn try_from(rect: Rect<T, U>) -> Result<Self, Rect<T, U>> {
+        if rect.size().width().is_zero() || rect.size().height().is_zero() {
+            return Err(rect);
+        }
+        Ok(rect.origin()..=rect.max().map(|n: T| n - T::one()))
+    }
+}
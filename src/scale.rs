@@ -0,0 +1,345 @@
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::unit::UnknownUnit;
+use crate::vector::Vector;
+use num;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Div, Mul};
+
+/// A per-axis scale factor converting coordinates in `Src` units to `Dst` units.
+///
+/// Dividing by a scale is only defined for integers and rounds outwards like `tile_at` and
+/// `tiles_covering`; floats are converted back with `inverse`.
+///
+/// # Examples
+/// ```
+/// # use coord::Point;
+/// # use coord::Scale;
+/// enum Tile {}
+/// enum Pixel {}
+/// let scale: Scale<i32, Tile, Pixel> = Scale::new(16, 8);
+/// let tile: Point<i32, Tile> = Point::typed(3, 4);
+/// assert_eq!(Point::<i32, Pixel>::typed(48, 32), tile * scale);
+/// assert_eq!(tile, tile * scale / scale);
+/// ```
+pub struct Scale<T: Primitive, Src = UnknownUnit, Dst = UnknownUnit> {
+    x: T,
+    y: T,
+    unit: PhantomData<(Src, Dst)>,
+}
+
+impl_unit_traits!(Scale<Src, Dst> { x, y });
+
+impl<T: Primitive, Src, Dst> Scale<T, Src, Dst> {
+    pub fn new(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// assert_eq!(Scale::<_>::new(2, 2), Scale::uniform(2));
+    /// ```
+    pub fn uniform(n: T) -> Self {
+        Self::new(n, n)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(3, 4), Point::new(3, 4) * Scale::identity());
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: num::One,
+    {
+        Self::uniform(T::one())
+    }
+
+    /// Returns the scale converting one tile of `tile_size` in `Dst` units to one `Src` unit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// # use coord::Size;
+    /// assert_eq!(Scale::<_>::new(16, 8), Scale::from_tile_size(Size::new(16, 8)));
+    /// ```
+    pub fn from_tile_size(tile_size: Size<T, Dst>) -> Self {
+        Self::new(tile_size.width(), tile_size.height())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// assert_eq!(2, Scale::<_>::new(2, 3).x());
+    /// ```
+    pub fn x(self) -> T {
+        self.x
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// assert_eq!(3, Scale::<_>::new(2, 3).y());
+    /// ```
+    pub fn y(self) -> T {
+        self.y
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// assert_eq!(Scale::<_>::new(0.5, 0.25), Scale::<_>::new(2.0, 4.0).inverse());
+    /// ```
+    pub fn inverse(self) -> Scale<T, Dst, Src>
+    where
+        T: num::Float,
+    {
+        Scale::new(self.x().recip(), self.y().recip())
+    }
+
+    /// Composes the scale with `other`, which is applied after this scale.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// assert_eq!(Scale::<_>::new(6, 20), Scale::<_>::new(2, 4).then(Scale::new(3, 5)));
+    /// ```
+    pub fn then<Dst2>(self, other: Scale<T, Dst, Dst2>) -> Scale<T, Src, Dst2>
+    where
+        T: Mul<T, Output = T>,
+    {
+        Scale::new(self.x() * other.x(), self.y() * other.y())
+    }
+
+    pub fn transform_point(self, point: Point<T, Src>) -> Point<T, Dst>
+    where
+        T: Mul<T, Output = T>,
+    {
+        Point::typed(point.x() * self.x(), point.y() * self.y())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// # use coord::Vector;
+    /// assert_eq!(Vector::new(6, -8), Scale::<_>::new(2, 4).transform_vector(Vector::new(3, -2)));
+    /// ```
+    pub fn transform_vector(self, vector: Vector<T, Src>) -> Vector<T, Dst>
+    where
+        T: Mul<T, Output = T>,
+    {
+        Vector::typed(vector.x() * self.x(), vector.y() * self.y())
+    }
+
+    pub fn transform_size(self, size: Size<T, Src>) -> Size<T, Dst>
+    where
+        T: Mul<T, Output = T>,
+    {
+        Size::typed(size.width() * self.x(), size.height() * self.y())
+    }
+
+    pub fn transform_rect(self, rect: Rect<T, Src>) -> Rect<T, Dst>
+    where
+        T: Mul<T, Output = T>,
+    {
+        Rect::typed(
+            self.transform_point(rect.origin()),
+            self.transform_size(rect.size()),
+        )
+    }
+}
+
+impl<T: Primitive + num::Integer, Tile, Pixel> Scale<T, Tile, Pixel> {
+    /// Returns the tile containing `point`, rounding towards negative infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// # use coord::Size;
+    /// # use coord::Point;
+    /// let tiles = Scale::from_tile_size(Size::new(16, 16));
+    /// assert_eq!(Point::new(1, 0), tiles.tile_at(Point::new(31, 15)));
+    /// assert_eq!(Point::new(-1, -2), tiles.tile_at(Point::new(-1, -17)));
+    /// ```
+    pub fn tile_at(self, point: Point<T, Pixel>) -> Point<T, Tile> {
+        point / self
+    }
+
+    /// Returns the rect covered by `tile`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// # use coord::Size;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// let tiles = Scale::from_tile_size(Size::new(16, 8));
+    /// assert_eq!(Rect::new(Point::new(32, -8), Size::new(16, 8)), tiles.tile_rect(Point::new(2, -1)));
+    /// ```
+    pub fn tile_rect(self, tile: Point<T, Tile>) -> Rect<T, Pixel> {
+        Rect::typed(self.transform_point(tile), Size::typed(self.x(), self.y()))
+    }
+
+    /// Returns the smallest rect of tiles which covers `rect`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// # use coord::Size;
+    /// # use coord::Point;
+    /// # use coord::Rect;
+    /// let tiles = Scale::from_tile_size(Size::new(16, 16));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(-1, 0), Size::new(4, 1)),
+    ///     tiles.tiles_covering(Rect::new(Point::new(-4, 2), Size::new(40, 14))));
+    /// ```
+    pub fn tiles_covering(self, rect: Rect<T, Pixel>) -> Rect<T, Tile> {
+        let min = self.tile_at(rect.origin());
+        let max = rect.origin() + rect.size();
+        let max = Point::typed(max.x().div_ceil(&self.x()), max.y().div_ceil(&self.y()));
+        Rect::from_corners(min, max)
+    }
+}
+
+impl<T: Primitive + fmt::Display, Src, Dst> fmt::Display for Scale<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x(), self.y())
+    }
+}
+
+impl<T: Primitive + Mul<T, Output = T>, Src, Dst, Dst2> Mul<Scale<T, Dst, Dst2>>
+    for Scale<T, Src, Dst>
+{
+    type Output = Scale<T, Src, Dst2>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Scale;
+    /// assert_eq!(Scale::<_>::new(6, 20), Scale::<_>::new(2, 4) * Scale::new(3, 5));
+    /// ```
+    fn mul(self, rhs: Scale<T, Dst, Dst2>) -> Self::Output {
+        self.then(rhs)
+    }
+}
+
+impl<T: Primitive + Mul<T, Output = T>, Src, Dst> Mul<Scale<T, Src, Dst>> for Point<T, Src> {
+    type Output = Point<T, Dst>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Scale;
+    /// assert_eq!(Point::new(6, 20), Point::new(3, 5) * Scale::new(2, 4));
+    /// ```
+    fn mul(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        rhs.transform_point(self)
+    }
+}
+
+impl<T: Primitive + num::Integer, Src, Dst> Div<Scale<T, Src, Dst>> for Point<T, Dst> {
+    type Output = Point<T, Src>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// # use coord::Scale;
+    /// assert_eq!(Point::new(3, 5), Point::new(6, 20) / Scale::new(2, 4));
+    /// assert_eq!(Point::new(-2, 4), Point::new(-3, 19) / Scale::new(2, 4));
+    /// ```
+    fn div(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        Point::typed(self.x().div_floor(&rhs.x()), self.y().div_floor(&rhs.y()))
+    }
+}
+
+impl<T: Primitive + Mul<T, Output = T>, Src, Dst> Mul<Scale<T, Src, Dst>> for Vector<T, Src> {
+    type Output = Vector<T, Dst>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector;
+    /// # use coord::Scale;
+    /// assert_eq!(Vector::new(-6, 20), Vector::new(-3, 5) * Scale::new(2, 4));
+    /// ```
+    fn mul(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        rhs.transform_vector(self)
+    }
+}
+
+impl<T: Primitive + Mul<T, Output = T>, Src, Dst> Mul<Scale<T, Src, Dst>> for Size<T, Src> {
+    type Output = Size<T, Dst>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// # use coord::Scale;
+    /// assert_eq!(Size::new(6, 20), Size::new(3, 5) * Scale::new(2, 4));
+    /// ```
+    fn mul(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        rhs.transform_size(self)
+    }
+}
+
+impl<T: Primitive + num::Integer, Src, Dst> Div<Scale<T, Src, Dst>> for Size<T, Dst> {
+    type Output = Size<T, Src>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// # use coord::Scale;
+    /// assert_eq!(Size::new(3, 5), Size::new(6, 20) / Scale::new(2, 4));
+    /// ```
+    fn div(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        Size::typed(
+            self.width().div_floor(&rhs.x()),
+            self.height().div_floor(&rhs.y()),
+        )
+    }
+}
+
+impl<T: Primitive + Mul<T, Output = T>, Src, Dst> Mul<Scale<T, Src, Dst>> for Rect<T, Src> {
+    type Output = Rect<T, Dst>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Scale;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(2, 8), Size::new(6, 20)),
+    ///     Rect::new(Point::new(1, 2), Size::new(3, 5)) * Scale::new(2, 4));
+    /// ```
+    fn mul(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        rhs.transform_rect(self)
+    }
+}
+
+impl<T: Primitive + num::Integer, Src, Dst> Div<Scale<T, Src, Dst>> for Rect<T, Dst> {
+    type Output = Rect<T, Src>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Scale;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(1, 2), Size::new(3, 5)),
+    ///     Rect::new(Point::new(2, 8), Size::new(6, 20)) / Scale::new(2, 4));
+    /// assert_eq!(
+    ///     Rect::new(Point::new(0, 0), Size::new(2, 1)),
+    ///     Rect::new(Point::new(1, 0), Size::new(2, 2)) / Scale::new(2, 2));
+    /// ```
+    fn div(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        rhs.tiles_covering(self)
+    }
+}
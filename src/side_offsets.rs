@@ -0,0 +1,308 @@
+use crate::coord::Primitive;
+use crate::size::Size;
+use num;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SideOffsets<T: Primitive> {
+    top: T,
+    right: T,
+    bottom: T,
+    left: T,
+}
+
+pub type Margins<T> = SideOffsets<T>;
+
+impl<T: Primitive> SideOffsets<T> {
+    pub fn new(top: T, right: T, bottom: T, left: T) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(4, 4, 4, 4), SideOffsets::uniform(4));
+    /// ```
+    pub fn uniform(n: T) -> Self {
+        Self::new(n, n, n, n)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(2, 8, 2, 8), SideOffsets::symmetric(2, 8));
+    /// ```
+    pub fn symmetric(vertical: T, horizontal: T) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(1, SideOffsets::new(1, 2, 3, 4).top());
+    /// ```
+    pub fn top(self) -> T {
+        self.top
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(2, SideOffsets::new(1, 2, 3, 4).right());
+    /// ```
+    pub fn right(self) -> T {
+        self.right
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(3, SideOffsets::new(1, 2, 3, 4).bottom());
+    /// ```
+    pub fn bottom(self) -> T {
+        self.bottom
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(4, SideOffsets::new(1, 2, 3, 4).left());
+    /// ```
+    pub fn left(self) -> T {
+        self.left
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(6, SideOffsets::new(1, 2, 3, 4).horizontal());
+    /// ```
+    pub fn horizontal(self) -> <T as Add<T>>::Output
+    where
+        T: Add<T>,
+    {
+        self.left() + self.right()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(4, SideOffsets::new(1, 2, 3, 4).vertical());
+    /// ```
+    pub fn vertical(self) -> <T as Add<T>>::Output
+    where
+        T: Add<T>,
+    {
+        self.top() + self.bottom()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(6, 4), SideOffsets::new(1, 2, 3, 4).size());
+    /// ```
+    pub fn size(self) -> Size<T>
+    where
+        T: Add<T, Output = T>,
+    {
+        Size::new(self.horizontal(), self.vertical())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(0, 0, 0, 0), SideOffsets::zero());
+    /// ```
+    pub fn zero() -> Self
+    where
+        T: num::Zero,
+    {
+        Self::uniform(T::zero())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert!(SideOffsets::new(0, 0, 0, 0).is_zero());
+    /// assert!(!SideOffsets::new(0, 1, 0, 0).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool
+    where
+        T: num::Zero,
+    {
+        self.top().is_zero()
+            && self.right().is_zero()
+            && self.bottom().is_zero()
+            && self.left().is_zero()
+    }
+
+    fn map<R: Primitive>(self, mut f: impl FnMut(T) -> R) -> SideOffsets<R> {
+        SideOffsets::new(f(self.top), f(self.right), f(self.bottom), f(self.left))
+    }
+
+    fn zip<U: Primitive, R: Primitive>(
+        self,
+        other: SideOffsets<U>,
+        mut f: impl FnMut(T, U) -> R,
+    ) -> SideOffsets<R> {
+        SideOffsets::new(
+            f(self.top, other.top),
+            f(self.right, other.right),
+            f(self.bottom, other.bottom),
+            f(self.left, other.left),
+        )
+    }
+}
+
+impl<T: Primitive + fmt::Display> fmt::Display for SideOffsets<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}, {}, {}, {})",
+            self.top(),
+            self.right(),
+            self.bottom(),
+            self.left()
+        )
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>> Add<SideOffsets<RHSP>>
+    for SideOffsets<T>
+{
+    type Output = SideOffsets<<T as Add<RHSP>>::Output>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(11, 22, 33, 44), SideOffsets::new(1, 2, 3, 4) + SideOffsets::new(10, 20, 30, 40));
+    /// ```
+    fn add(self, rhs: SideOffsets<RHSP>) -> Self::Output {
+        self.zip(rhs, |n, m| n + m)
+    }
+}
+
+impl<T: Primitive, RHS> AddAssign<RHS> for SideOffsets<T>
+where
+    SideOffsets<T>: Add<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// let mut o = SideOffsets::new(1, 2, 3, 4);
+    /// o += SideOffsets::uniform(10);
+    /// assert_eq!(SideOffsets::new(11, 12, 13, 14), o);
+    /// ```
+    fn add_assign(&mut self, rhs: RHS) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Primitive + Neg<Output = impl Primitive>> Neg for SideOffsets<T> {
+    type Output = SideOffsets<<T as Neg>::Output>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(-1, -2, -3, -4), -SideOffsets::new(1, 2, 3, 4));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.map(|n| -n)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<SideOffsets<RHSP>>
+    for SideOffsets<T>
+{
+    type Output = SideOffsets<<T as Sub<RHSP>>::Output>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(1, 2, 3, 4), SideOffsets::new(11, 22, 33, 44) - SideOffsets::new(10, 20, 30, 40));
+    /// ```
+    fn sub(self, rhs: SideOffsets<RHSP>) -> Self::Output {
+        self.zip(rhs, |n, m| n - m)
+    }
+}
+
+impl<T: Primitive, RHS> SubAssign<RHS> for SideOffsets<T>
+where
+    SideOffsets<T>: Sub<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// let mut o = SideOffsets::new(11, 12, 13, 14);
+    /// o -= SideOffsets::uniform(10);
+    /// assert_eq!(SideOffsets::new(1, 2, 3, 4), o);
+    /// ```
+    fn sub_assign(&mut self, rhs: RHS) {
+        *self = *self - rhs;
+    }
+}
+
+impl<RHSP: Copy, T: Primitive + Mul<RHSP, Output = impl Primitive>> Mul<RHSP> for SideOffsets<T> {
+    type Output = SideOffsets<<T as Mul<RHSP>>::Output>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(2, 4, 6, 8), SideOffsets::new(1, 2, 3, 4) * 2);
+    /// ```
+    fn mul(self, rhs: RHSP) -> Self::Output {
+        self.map(|n| n * rhs)
+    }
+}
+
+impl<T: Primitive, RHS> MulAssign<RHS> for SideOffsets<T>
+where
+    SideOffsets<T>: Mul<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// let mut o = SideOffsets::new(1, 2, 3, 4);
+    /// o *= 2;
+    /// assert_eq!(SideOffsets::new(2, 4, 6, 8), o);
+    /// ```
+    fn mul_assign(&mut self, rhs: RHS) {
+        *self = *self * rhs;
+    }
+}
+
+impl<RHSP: Copy, T: Primitive + Div<RHSP, Output = impl Primitive>> Div<RHSP> for SideOffsets<T> {
+    type Output = SideOffsets<<T as Div<RHSP>>::Output>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// assert_eq!(SideOffsets::new(1, 2, 3, 4), SideOffsets::new(2, 4, 6, 8) / 2);
+    /// ```
+    fn div(self, rhs: RHSP) -> Self::Output {
+        self.map(|n| n / rhs)
+    }
+}
+
+impl<T: Primitive, RHS> DivAssign<RHS> for SideOffsets<T>
+where
+    SideOffsets<T>: Div<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::SideOffsets;
+    /// let mut o = SideOffsets::new(2, 4, 6, 8);
+    /// o /= 2;
+    /// assert_eq!(SideOffsets::new(1, 2, 3, 4), o);
+    /// ```
+    fn div_assign(&mut self, rhs: RHS) {
+        *self = *self / rhs;
+    }
+}
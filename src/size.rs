@@ -1,16 +1,30 @@
 use crate::coord::{Coord, Primitive};
+use crate::unit::UnknownUnit;
 use std::fmt;
+use std::marker::PhantomData;
 use std::ops::Mul;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Size<T: Primitive> {
+pub struct Size<T: Primitive, U = UnknownUnit> {
     width: T,
     height: T,
+    unit: PhantomData<U>,
 }
 
+impl_unit_traits!(Size { width, height });
+
 impl<T: Primitive> Size<T> {
     pub fn new(width: T, height: T) -> Self {
-        Self { width, height }
+        Self::typed(width, height)
+    }
+}
+
+impl<T: Primitive, U> Size<T, U> {
+    pub fn typed(width: T, height: T) -> Self {
+        Self {
+            width,
+            height,
+            unit: PhantomData,
+        }
     }
 
     /// # Examples
@@ -42,10 +56,36 @@ impl<T: Primitive> Size<T> {
     {
         self.width() * self.height()
     }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size;
+    /// enum Tile {}
+    /// let s: Size<_, Tile> = Size::new(3, 4).cast_unit();
+    /// assert_eq!(Size::typed(3, 4), s);
+    /// ```
+    pub fn cast_unit<V>(self) -> Size<T, V> {
+        Size::typed(self.width(), self.height())
+    }
+
+    pub fn to_untyped(self) -> Size<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(size: Size<T>) -> Self {
+        size.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, U> Default for Size<T, U> {
+    fn default() -> Self {
+        Self::typed(T::default(), T::default())
+    }
 }
 
-impl<T: Primitive> Coord for Size<T> {
+impl<T: Primitive, U> Coord for Size<T, U> {
     type Item = T;
+    type Unit = U;
 
     /// # Examples
     /// ```
@@ -54,14 +94,14 @@ impl<T: Primitive> Coord for Size<T> {
     /// assert_eq!(Size::new(3, 4), Size::from_x_y(3, 4));
     /// ```
     fn from_x_y(x: T, y: T) -> Self {
-        Self::new(x, y)
+        Self::typed(x, y)
     }
 
     /// # Examples
     /// ```
     /// # use coord::Size;
     /// # use coord::Coord;
-    /// assert_eq!(5, Size::from_x_y(5, 7).x());
+    /// assert_eq!(5, Size::<_>::from_x_y(5, 7).x());
     /// ```
     fn x(self) -> T {
         self.width()
@@ -71,14 +111,14 @@ impl<T: Primitive> Coord for Size<T> {
     /// ```
     /// # use coord::Size;
     /// # use coord::Coord;
-    /// assert_eq!(7, Size::from_x_y(5, 7).y());
+    /// assert_eq!(7, Size::<_>::from_x_y(5, 7).y());
     /// ```
     fn y(self) -> T {
         self.height()
     }
 }
 
-impl<T: Primitive + fmt::Display> fmt::Display for Size<T> {
+impl<T: Primitive + fmt::Display, U> fmt::Display for Size<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.width(), self.height())
     }
@@ -0,0 +1,132 @@
+use crate::coord::Primitive;
+use crate::coord3::Coord3;
+use crate::unit::UnknownUnit;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Mul;
+
+pub struct Size3<T: Primitive, U = UnknownUnit> {
+    width: T,
+    height: T,
+    depth: T,
+    unit: PhantomData<U>,
+}
+
+impl_unit_traits!(Size3 {
+    width,
+    height,
+    depth
+});
+
+impl<T: Primitive> Size3<T> {
+    pub fn new(width: T, height: T, depth: T) -> Self {
+        Self::typed(width, height, depth)
+    }
+}
+
+impl<T: Primitive, U> Size3<T, U> {
+    pub fn typed(width: T, height: T, depth: T) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size3;
+    /// assert_eq!(3, Size3::new(3, 4, 5).width());
+    /// ```
+    pub fn width(self) -> T {
+        self.width
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size3;
+    /// assert_eq!(4, Size3::new(3, 4, 5).height());
+    /// ```
+    pub fn height(self) -> T {
+        self.height
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size3;
+    /// assert_eq!(5, Size3::new(3, 4, 5).depth());
+    /// ```
+    pub fn depth(self) -> T {
+        self.depth
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size3;
+    /// assert_eq!(60, Size3::new(3, 4, 5).volume());
+    /// ```
+    pub fn volume(self) -> T
+    where
+        T: Mul<T, Output = T>,
+    {
+        self.width() * self.height() * self.depth()
+    }
+
+    pub fn cast_unit<V>(self) -> Size3<T, V> {
+        Size3::typed(self.width(), self.height(), self.depth())
+    }
+
+    pub fn to_untyped(self) -> Size3<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(size: Size3<T>) -> Self {
+        size.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, U> Default for Size3<T, U> {
+    fn default() -> Self {
+        Self::typed(T::default(), T::default(), T::default())
+    }
+}
+
+impl<T: Primitive, U> Coord3 for Size3<T, U> {
+    type Item = T;
+    type Unit = U;
+
+    fn from_x_y_z(x: T, y: T, z: T) -> Self {
+        Self::typed(x, y, z)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size3;
+    /// # use coord::Coord3;
+    /// assert_eq!(3, Size3::<_>::from_x_y_z(3, 4, 5).x());
+    /// ```
+    fn x(self) -> T {
+        self.width()
+    }
+
+    fn y(self) -> T {
+        self.height()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Size3;
+    /// # use coord::Coord3;
+    /// assert_eq!(5, Size3::<_>::from_x_y_z(3, 4, 5).z());
+    /// ```
+    fn z(self) -> T {
+        self.depth()
+    }
+}
+
+impl<T: Primitive + fmt::Display, U> fmt::Display for Size3<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.width(), self.height(), self.depth())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::coord::Primitive;
+use crate::coord_n::CoordN;
+use crate::size::Size;
+use crate::unit::UnknownUnit;
+use num;
+use std::array;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+/// A size of `N` components, which are indexed from 0 in the order width, height, depth, and so on.
+pub struct SizeN<T: Primitive, const N: usize, U = UnknownUnit> {
+    components: [T; N],
+    unit: PhantomData<U>,
+}
+
+impl_unit_traits!(SizeN<const N, U> { components });
+
+impl<T: Primitive, const N: usize> SizeN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self::typed(components)
+    }
+}
+
+impl<T: Primitive, const N: usize, U> SizeN<T, N, U> {
+    pub fn typed(components: [T; N]) -> Self {
+        Self {
+            components,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SizeN;
+    /// assert_eq!([4, 8, 2, 6], SizeN::new([4, 8, 2, 6]).components());
+    /// ```
+    pub fn components(self) -> [T; N] {
+        self.components
+    }
+
+    pub fn cast_unit<V>(self) -> SizeN<T, N, V> {
+        SizeN::typed(self.components)
+    }
+
+    pub fn to_untyped(self) -> SizeN<T, N> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(size: SizeN<T, N>) -> Self {
+        size.cast_unit()
+    }
+
+    /// Returns the product of the components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SizeN;
+    /// assert_eq!(120, SizeN::new([2, 3, 4, 5]).volume());
+    /// ```
+    pub fn volume(self) -> T
+    where
+        T: num::One,
+    {
+        self.components
+            .iter()
+            .fold(T::one(), |product, &n| product * n)
+    }
+}
+
+impl<T: Primitive + Default, const N: usize, U> Default for SizeN<T, N, U> {
+    fn default() -> Self {
+        Self::typed(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Primitive, const N: usize, U> CoordN<N> for SizeN<T, N, U> {
+    type Item = T;
+    type Unit = U;
+
+    fn from_array(components: [T; N]) -> Self {
+        Self::typed(components)
+    }
+
+    fn to_array(self) -> [T; N] {
+        self.components()
+    }
+}
+
+impl<T: Primitive, const N: usize, U> Index<usize> for SizeN<T, N, U> {
+    type Output = T;
+
+    /// # Examples
+    /// ```
+    /// # use coord::SizeN;
+    /// assert_eq!(2, SizeN::new([4, 8, 2, 6])[2]);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<T: Primitive, const N: usize, U> IndexMut<usize> for SizeN<T, N, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::SizeN;
+    /// let mut p = SizeN::new([4, 8, 2, 6]);
+    /// p[3] = 10;
+    /// assert_eq!(SizeN::new([4, 8, 2, 10]), p);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.components[index]
+    }
+}
+
+impl<T: Primitive, U> From<Size<T, U>> for SizeN<T, 2, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::SizeN;
+    /// # use coord::Size;
+    /// assert_eq!(SizeN::new([3, 4]), SizeN::from(Size::new(3, 4)));
+    /// ```
+    fn from(size: Size<T, U>) -> Self {
+        Self::typed([size.width(), size.height()])
+    }
+}
+
+impl<T: Primitive, U> From<SizeN<T, 2, U>> for Size<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::SizeN;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(3, 4), Size::from(SizeN::new([3, 4])));
+    /// ```
+    fn from(size: SizeN<T, 2, U>) -> Self {
+        let [width, height] = size.components();
+        Size::typed(width, height)
+    }
+}
@@ -0,0 +1,241 @@
+use crate::bounding_box::BoundingBox;
+use crate::point::Point;
+use crate::rect::Rect;
+use std::collections::HashMap;
+use std::ops::Index;
+
+/// An unbounded grid storing only the cells which have been set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<i32>, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// let grid = SparseGrid::<char>::new();
+    /// assert!(grid.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(1, 2), 'a');
+    /// grid.insert(Point::new(1, 2), 'b');
+    /// grid.insert(Point::new(-1, 2), 'c');
+    /// assert_eq!(2, grid.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// assert!(grid.is_empty());
+    /// grid.insert(Point::new(1, 2), 'a');
+    /// assert!(!grid.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(-100, 42), 'x');
+    /// assert_eq!(Some(&'x'), grid.get(Point::new(-100, 42)));
+    /// assert_eq!(None, grid.get(Point::new(0, 0)));
+    /// ```
+    pub fn get(&self, point: Point<i32>) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(-1, 3), 1);
+    /// *grid.get_mut(Point::new(-1, 3)).unwrap() += 4;
+    /// assert_eq!(5, grid[Point::new(-1, 3)]);
+    /// assert_eq!(None, grid.get_mut(Point::new(0, 0)));
+    /// ```
+    pub fn get_mut(&mut self, point: Point<i32>) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the cell at `point`, returning the previous value if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// assert_eq!(None, grid.insert(Point::new(1, 2), 5));
+    /// assert_eq!(Some(5), grid.insert(Point::new(1, 2), 6));
+    /// ```
+    pub fn insert(&mut self, point: Point<i32>, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(1, 2), 5);
+    /// assert_eq!(Some(5), grid.remove(Point::new(1, 2)));
+    /// assert_eq!(None, grid.remove(Point::new(1, 2)));
+    /// ```
+    pub fn remove(&mut self, point: Point<i32>) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Returns the cell at `point`, setting it with `f` first if it is not set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// *grid.get_or_insert_with(Point::new(0, -4), Vec::new) = vec![1];
+    /// grid.get_or_insert_with(Point::new(0, -4), Vec::new).push(2);
+    /// assert_eq!(vec![1, 2], grid[Point::new(0, -4)]);
+    /// ```
+    pub fn get_or_insert_with(&mut self, point: Point<i32>, f: impl FnOnce() -> T) -> &mut T {
+        self.cells.entry(point).or_insert_with(f)
+    }
+
+    /// Iterates the set cells with their points in arbitrary order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(3, -1), 'a');
+    /// assert_eq!(vec![(Point::new(3, -1), &'a')], grid.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i32>, &T)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Iterates the set cells mutably with their points in arbitrary order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(3, -1), 0);
+    /// for (p, cell) in grid.iter_mut() {
+    ///     *cell = p.x() - p.y();
+    /// }
+    /// assert_eq!(4, grid[Point::new(3, -1)]);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<i32>, &mut T)> {
+        self.cells.iter_mut().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Returns the smallest rect covering all the set cells, or `None` if no cell is set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut grid = SparseGrid::new();
+    /// assert_eq!(None, grid.bounds());
+    /// grid.insert(Point::new(-3, 2), ());
+    /// grid.insert(Point::new(4, -1), ());
+    /// assert_eq!(Some(Rect::new(Point::new(-3, -1), Size::new(8, 4))), grid.bounds());
+    /// ```
+    pub fn bounds(&self) -> Option<Rect<i32>> {
+        self.cells
+            .keys()
+            .copied()
+            .collect::<BoundingBox<_, _>>()
+            .cell_rect()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// assert!(SparseGrid::<char>::default().is_empty());
+    /// ```
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<Point<i32>> for SparseGrid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if the cell at `point` is not set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(-100, 42), 'x');
+    /// assert_eq!('x', grid[Point::new(-100, 42)]);
+    /// ```
+    fn index(&self, point: Point<i32>) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{} is not set", point),
+        }
+    }
+}
+
+impl<T> std::iter::FromIterator<(Point<i32>, T)> for SparseGrid<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let grid = vec![(Point::new(0, 0), 'a'), (Point::new(5, -5), 'b')]
+    ///     .into_iter()
+    ///     .collect::<SparseGrid<_>>();
+    /// assert_eq!(2, grid.len());
+    /// assert_eq!('b', grid[Point::new(5, -5)]);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (Point<i32>, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point<i32>, T)> for SparseGrid<T> {
+    /// # Examples
+    /// ```
+    /// # use coord::SparseGrid;
+    /// # use coord::Point;
+    /// let mut grid = SparseGrid::new();
+    /// grid.insert(Point::new(0, 0), 'a');
+    /// grid.extend(vec![(Point::new(0, 0), 'b'), (Point::new(1, 0), 'c')]);
+    /// assert_eq!(2, grid.len());
+    /// assert_eq!('b', grid[Point::new(0, 0)]);
+    /// ```
+    fn extend<I: IntoIterator<Item = (Point<i32>, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
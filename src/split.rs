@@ -0,0 +1,159 @@
+use crate::axis::{Axis, AxisKeyed};
+use crate::component_wise::ComponentWise;
+use crate::coord::Primitive;
+use crate::map::Map;
+use crate::point_range_iterator::PointStep;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::vector::Vector;
+use num;
+
+impl<T: Primitive + num::Num + PartialOrd, U> Rect<T, U> {
+    /// Splits the rect at `offset` from its origin along `axis`, clamping the offset into the rect.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// let rect = Rect::new(Point::new(10, 20), Size::new(100, 50));
+    /// assert_eq!(
+    ///     (Rect::new(Point::new(10, 20), Size::new(30, 50)), Rect::new(Point::new(40, 20), Size::new(70, 50))),
+    ///     rect.split_at(Axis::X, 30));
+    /// assert_eq!(
+    ///     (rect, Rect::new(Point::new(10, 70), Size::new(100, 0))),
+    ///     rect.split_at(Axis::Y, 80));
+    /// ```
+    pub fn split_at(self, axis: Axis, offset: T) -> (Self, Self) {
+        let (origin, size) = self.get(axis);
+        let offset = if offset < T::zero() {
+            T::zero()
+        } else if size < offset {
+            size
+        } else {
+            offset
+        };
+        (
+            self.set(axis, (origin, offset)),
+            self.set(axis, (origin + offset, size - offset)),
+        )
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// assert_eq!(
+    ///     (Rect::new(Point::new(0.0, 0.0), Size::new(10.0, 15.0)), Rect::new(Point::new(0.0, 15.0), Size::new(10.0, 5.0))),
+    ///     Rect::new(Point::new(0.0, 0.0), Size::new(10.0, 20.0)).split_fraction(Axis::Y, 0.75));
+    /// ```
+    pub fn split_fraction(self, axis: Axis, fraction: T) -> (Self, Self)
+    where
+        T: num::Float,
+    {
+        let (_, size) = self.get(axis);
+        self.split_at(axis, size * fraction)
+    }
+
+    /// Splits the rect into `n` parts along `axis`, giving the leading parts one more unit each
+    /// when the size is not divisible by `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Axis;
+    /// assert_eq!(
+    ///     vec![
+    ///         Rect::new(Point::new(10, 0), Size::new(4, 5)),
+    ///         Rect::new(Point::new(14, 0), Size::new(3, 5)),
+    ///         Rect::new(Point::new(17, 0), Size::new(3, 5)),
+    ///     ],
+    ///     Rect::new(Point::new(10, 0), Size::new(10, 5)).split_into(Axis::X, 3));
+    /// ```
+    pub fn split_into(self, axis: Axis, n: usize) -> Vec<Self>
+    where
+        T: num::PrimInt,
+    {
+        let count = match num::NumCast::from(n) {
+            Some(count) if n > 0 => count,
+            _ => return Vec::new(),
+        };
+        let (origin, size) = self.get(axis);
+        let (base, remainder) = (size / count, size % count);
+        let mut offset = origin;
+        let mut index = T::zero();
+        let mut parts = Vec::with_capacity(n);
+        while index < count {
+            let length = if index < remainder {
+                base + T::one()
+            } else {
+                base
+            };
+            parts.push(self.set(axis, (offset, length)));
+            offset = offset + length;
+            index = index + T::one();
+        }
+        parts
+    }
+
+    /// Splits the rect into `columns` x `rows` cells in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     vec![
+    ///         Rect::new(Point::new(0, 0), Size::new(3, 2)), Rect::new(Point::new(3, 0), Size::new(2, 2)),
+    ///         Rect::new(Point::new(0, 2), Size::new(3, 2)), Rect::new(Point::new(3, 2), Size::new(2, 2)),
+    ///     ],
+    ///     Rect::new(Point::new(0, 0), Size::new(5, 4)).grid_cells(2, 2));
+    /// ```
+    pub fn grid_cells(self, columns: usize, rows: usize) -> Vec<Self>
+    where
+        T: num::PrimInt,
+    {
+        self.split_into(Axis::Y, rows)
+            .into_iter()
+            .flat_map(|row| row.split_into(Axis::X, columns))
+            .collect()
+    }
+
+    /// Tiles the rect with `chunk_size` chunks in row-major order, clipping the trailing chunks
+    /// to the rect.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     vec![
+    ///         Rect::new(Point::new(10, 20), Size::new(4, 3)), Rect::new(Point::new(14, 20), Size::new(2, 3)),
+    ///         Rect::new(Point::new(10, 23), Size::new(4, 2)), Rect::new(Point::new(14, 23), Size::new(2, 2)),
+    ///     ],
+    ///     Rect::new(Point::new(10, 20), Size::new(6, 5)).chunks(Size::new(4, 3)).collect::<Vec<_>>());
+    /// ```
+    pub fn chunks(self, chunk_size: Size<T, U>) -> impl Iterator<Item = Self>
+    where
+        T: PointStep,
+    {
+        assert!(
+            T::zero() < chunk_size.width() && T::zero() < chunk_size.height(),
+            "chunk size must be positive"
+        );
+        let max = self.max();
+        self.size()
+            .points_step(self.origin(), chunk_size.map::<Vector<T, U>>(|n| n))
+            .map(move |p| Rect::typed(p, chunk_size.component_min((max, p).map(|(m, p)| m - p))))
+    }
+}
@@ -0,0 +1,259 @@
+use crate::axis::Axis;
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::transpose::Transpose;
+use crate::vector::Vector;
+use num;
+
+/// One of the eight rotations and reflections of a square, applied as an optional transpose
+/// followed by optional flips of x and y.
+///
+/// Rotations are clockwise on a y-down grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Symmetry {
+    transpose: bool,
+    flip_x: bool,
+    flip_y: bool,
+}
+
+impl Symmetry {
+    pub const IDENTITY: Symmetry = Symmetry::new(false, false, false);
+    pub const ROTATE_90: Symmetry = Symmetry::new(true, true, false);
+    pub const ROTATE_180: Symmetry = Symmetry::new(false, true, true);
+    pub const ROTATE_270: Symmetry = Symmetry::new(true, false, true);
+    pub const FLIP_X: Symmetry = Symmetry::new(false, true, false);
+    pub const FLIP_Y: Symmetry = Symmetry::new(false, false, true);
+    pub const TRANSPOSE: Symmetry = Symmetry::new(true, false, false);
+    pub const ANTI_TRANSPOSE: Symmetry = Symmetry::new(true, true, true);
+
+    /// The rotations followed by the reflections.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::IDENTITY,
+        Symmetry::ROTATE_90,
+        Symmetry::ROTATE_180,
+        Symmetry::ROTATE_270,
+        Symmetry::FLIP_X,
+        Symmetry::FLIP_Y,
+        Symmetry::TRANSPOSE,
+        Symmetry::ANTI_TRANSPOSE,
+    ];
+
+    pub const fn new(transpose: bool, flip_x: bool, flip_y: bool) -> Self {
+        Self {
+            transpose,
+            flip_x,
+            flip_y,
+        }
+    }
+
+    /// Returns the rotation by `quarter_turns` clockwise on a y-down grid; negative turns rotate
+    /// counterclockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// assert_eq!(Symmetry::ROTATE_270, Symmetry::rotation(-1));
+    /// assert_eq!(Symmetry::IDENTITY, Symmetry::rotation(8));
+    /// ```
+    pub fn rotation(quarter_turns: i32) -> Self {
+        Self::ALL[quarter_turns.rem_euclid(4) as usize]
+    }
+
+    pub fn transposes(self) -> bool {
+        self.transpose
+    }
+
+    pub fn flips_x(self) -> bool {
+        self.flip_x
+    }
+
+    pub fn flips_y(self) -> bool {
+        self.flip_y
+    }
+
+    /// Returns whether the symmetry is a reflection rather than a rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// assert!(Symmetry::TRANSPOSE.is_reflection());
+    /// assert!(!Symmetry::ROTATE_90.is_reflection());
+    /// ```
+    pub fn is_reflection(self) -> bool {
+        self.transpose ^ self.flip_x ^ self.flip_y
+    }
+
+    /// Returns the symmetry applying this symmetry and then `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// assert_eq!(Symmetry::ROTATE_180, Symmetry::ROTATE_90.then(Symmetry::ROTATE_90));
+    /// assert_eq!(Symmetry::ROTATE_90, Symmetry::FLIP_Y.then(Symmetry::TRANSPOSE));
+    /// ```
+    pub fn then(self, other: Self) -> Self {
+        let image = other.transform_vector(self.transform_vector(Vector::new(1i32, 2)));
+        Self::new(image.x().abs() == 2, image.x() < 0, image.y() < 0)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// assert_eq!(Symmetry::ROTATE_270, Symmetry::ROTATE_90.inverse());
+    /// assert_eq!(Symmetry::FLIP_X, Symmetry::FLIP_X.inverse());
+    /// ```
+    pub fn inverse(self) -> Self {
+        if self.transpose {
+            Self::new(true, self.flip_y, self.flip_x)
+        } else {
+            self
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// # use coord::Axis;
+    /// assert_eq!(Axis::Y, Symmetry::ROTATE_90.transform_axis(Axis::X));
+    /// assert_eq!(Axis::X, Symmetry::FLIP_Y.transform_axis(Axis::X));
+    /// ```
+    pub fn transform_axis(self, axis: Axis) -> Axis {
+        if self.transpose {
+            axis.transpose()
+        } else {
+            axis
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// # use coord::Vector;
+    /// assert_eq!(Vector::new(-2, 1), Symmetry::ROTATE_90.transform_vector(Vector::new(1, 2)));
+    /// assert_eq!(Vector::new(1, -2), Symmetry::FLIP_Y.transform_vector(Vector::new(1, 2)));
+    /// ```
+    pub fn transform_vector<T: Primitive + num::Signed, U>(
+        self,
+        vector: Vector<T, U>,
+    ) -> Vector<T, U> {
+        let vector = if self.transpose {
+            vector.transpose()
+        } else {
+            vector
+        };
+        Vector::typed(
+            if self.flip_x { -vector.x() } else { vector.x() },
+            if self.flip_y { -vector.y() } else { vector.y() },
+        )
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(3, 5), Symmetry::ROTATE_270.transform_size(Size::new(5, 3)));
+    /// assert_eq!(Size::new(5, 3), Symmetry::ROTATE_180.transform_size(Size::new(5, 3)));
+    /// ```
+    pub fn transform_size<T: Primitive, U>(self, size: Size<T, U>) -> Size<T, U> {
+        if self.transpose {
+            size.transpose()
+        } else {
+            size
+        }
+    }
+
+    /// Transforms `point` around `pivot`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(8, 11), Symmetry::ROTATE_90.transform_point_around(Point::new(11, 12), Point::new(10, 10)));
+    /// ```
+    pub fn transform_point_around<T: Primitive + num::Signed, U>(
+        self,
+        point: Point<T, U>,
+        pivot: Point<T, U>,
+    ) -> Point<T, U> {
+        pivot + self.transform_vector(point - pivot)
+    }
+
+    /// Transforms the cell at `point` in a grid of `size` into the corresponding cell in the
+    /// transformed grid of `transform_size(size)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let size = Size::new(4u32, 3);
+    /// assert_eq!(Point::new(2, 0), Symmetry::ROTATE_90.transform_cell(Point::new(0, 0), size));
+    /// assert_eq!(Point::new(0, 3), Symmetry::ROTATE_90.transform_cell(Point::new(3, 2), size));
+    /// assert_eq!(Point::new(3, 2), Symmetry::ROTATE_180.transform_cell(Point::new(0, 0), size));
+    /// ```
+    pub fn transform_cell<T: Primitive + num::Num, U>(
+        self,
+        point: Point<T, U>,
+        size: Size<T, U>,
+    ) -> Point<T, U> {
+        let (point, size) = if self.transpose {
+            (point.transpose(), size.transpose())
+        } else {
+            (point, size)
+        };
+        Point::typed(
+            if self.flip_x {
+                size.width() - T::one() - point.x()
+            } else {
+                point.x()
+            },
+            if self.flip_y {
+                size.height() - T::one() - point.y()
+            } else {
+                point.y()
+            },
+        )
+    }
+
+    /// Transforms `rect` within a space of `size` into the transformed space of
+    /// `transform_size(size)`, which maps the cells in the rect to the transformed cells.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Symmetry;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(1, 1), Size::new(2, 3)),
+    ///     Symmetry::ROTATE_90.transform_rect(Rect::new(Point::new(1, 0), Size::new(3, 2)), Size::new(5, 3)));
+    /// ```
+    pub fn transform_rect<T: Primitive + num::Num, U>(
+        self,
+        rect: Rect<T, U>,
+        size: Size<T, U>,
+    ) -> Rect<T, U> {
+        let (rect, size) = if self.transpose {
+            (rect.transpose(), size.transpose())
+        } else {
+            (rect, size)
+        };
+        Rect::typed(
+            Point::typed(
+                if self.flip_x {
+                    size.width() - rect.right()
+                } else {
+                    rect.left()
+                },
+                if self.flip_y {
+                    size.height() - rect.bottom()
+                } else {
+                    rect.top()
+                },
+            ),
+            rect.size(),
+        )
+    }
+}
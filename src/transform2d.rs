@@ -0,0 +1,230 @@
+use crate::angle::{Angle, AnglePrimitive};
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::vector::Vector;
+use num;
+
+/// A 2D affine transform as a 3x2 matrix applied to row vectors, i.e.
+/// `x' = x * m11 + y * m21 + m31` and `y' = x * m12 + y * m22 + m32`.
+///
+/// Transforms are not tagged with units and keep the unit of whatever they are applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transform2D<T: Primitive> {
+    m11: T,
+    m12: T,
+    m21: T,
+    m22: T,
+    m31: T,
+    m32: T,
+}
+
+impl<T: Primitive + num::Float> Transform2D<T> {
+    pub fn new(m11: T, m12: T, m21: T, m22: T, m31: T, m32: T) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(3.0, 4.0), Transform2D::identity().transform_point(Point::new(3.0, 4.0)));
+    /// ```
+    pub fn identity() -> Self {
+        Self::scale(T::one(), T::one())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Point;
+    /// # use coord::Vector;
+    /// let t = Transform2D::translation(Vector::new(1.0, -2.0));
+    /// assert_eq!(Point::new(4.0, 2.0), t.transform_point(Point::new(3.0, 4.0)));
+    /// assert_eq!(Vector::new(3.0, 4.0), t.transform_vector(Vector::new(3.0, 4.0)));
+    /// ```
+    pub fn translation<U>(vector: Vector<T, U>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, zero, zero, one, vector.x(), vector.y())
+    }
+
+    /// Returns the rotation by `angle`, which turns the x axis towards the y axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Angle;
+    /// # use coord::Point;
+    /// # use coord::Map;
+    /// let p = Transform2D::rotation(Angle::<f64>::pi() / 2.0).transform_point(Point::new(3.0, 4.0));
+    /// assert_eq!(Point::new(-4.0, 3.0), p.map(|n: f64| n.round()));
+    /// ```
+    pub fn rotation(angle: Angle<T>) -> Self
+    where
+        T: AnglePrimitive,
+    {
+        let (sin, cos) = angle.radian().sin_cos();
+        Self::new(cos, sin, -sin, cos, T::zero(), T::zero())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(6.0, -4.0), Transform2D::scale(2.0, -1.0).transform_point(Point::new(3.0, 4.0)));
+    /// ```
+    pub fn scale(x: T, y: T) -> Self {
+        let zero = T::zero();
+        Self::new(x, zero, zero, y, zero, zero)
+    }
+
+    /// Returns the skew which shears x by `x` along y and y by `y` along x.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Angle;
+    /// # use coord::Point;
+    /// # use coord::Map;
+    /// let p = Transform2D::skew(Angle::<f64>::pi() / 4.0, Angle::new(0.0)).transform_point(Point::new(3.0, 4.0));
+    /// assert_eq!(Point::new(7.0, 4.0), p.map(|n: f64| n.round()));
+    /// ```
+    pub fn skew(x: Angle<T>, y: Angle<T>) -> Self
+    where
+        T: AnglePrimitive,
+    {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, y.radian().tan(), x.radian().tan(), one, zero, zero)
+    }
+
+    /// Returns the transform applying this transform and then `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Point;
+    /// # use coord::Vector;
+    /// let t = Transform2D::scale(2.0, 3.0).then(Transform2D::translation(Vector::new(1.0, 1.0)));
+    /// assert_eq!(Point::new(7.0, 13.0), t.transform_point(Point::new(3.0, 4.0)));
+    /// ```
+    pub fn then(self, other: Self) -> Self {
+        Self::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+
+    pub fn then_translate<U>(self, vector: Vector<T, U>) -> Self {
+        self.then(Self::translation(vector))
+    }
+
+    pub fn then_rotate(self, angle: Angle<T>) -> Self
+    where
+        T: AnglePrimitive,
+    {
+        self.then(Self::rotation(angle))
+    }
+
+    pub fn then_scale(self, x: T, y: T) -> Self {
+        self.then(Self::scale(x, y))
+    }
+
+    /// Returns the matrix as `[m11, m12, m21, m22, m31, m32]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// assert_eq!([2.0, 0.0, 0.0, 3.0, 0.0, 0.0], Transform2D::scale(2.0, 3.0).to_array());
+    /// ```
+    pub fn to_array(self) -> [T; 6] {
+        [self.m11, self.m12, self.m21, self.m22, self.m31, self.m32]
+    }
+
+    pub fn determinant(self) -> T {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// Returns the inverse transform, or `None` if the transform is not invertible.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Point;
+    /// # use coord::Vector;
+    /// let t = Transform2D::scale(2.0, 4.0).then_translate(Vector::new(1.0, -1.0));
+    /// assert_eq!(Point::new(3.0, 4.0), t.inverse().unwrap().transform_point(Point::new(7.0, 15.0)));
+    /// assert_eq!(None, Transform2D::scale(0.0, 1.0).inverse());
+    /// ```
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        Some(Self::new(
+            self.m22 / det,
+            -self.m12 / det,
+            -self.m21 / det,
+            self.m11 / det,
+            (self.m21 * self.m32 - self.m22 * self.m31) / det,
+            (self.m31 * self.m12 - self.m11 * self.m32) / det,
+        ))
+    }
+
+    pub fn transform_point<U>(self, point: Point<T, U>) -> Point<T, U> {
+        Point::typed(
+            point.x() * self.m11 + point.y() * self.m21 + self.m31,
+            point.x() * self.m12 + point.y() * self.m22 + self.m32,
+        )
+    }
+
+    /// Transforms `vector` by the linear part only, ignoring the translation.
+    pub fn transform_vector<U>(self, vector: Vector<T, U>) -> Vector<T, U> {
+        Vector::typed(
+            vector.x() * self.m11 + vector.y() * self.m21,
+            vector.x() * self.m12 + vector.y() * self.m22,
+        )
+    }
+
+    /// Returns the bounding rect of the transformed corners of `rect`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Transform2D;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(-6.0, 1.0), Size::new(4.0, 2.0)),
+    ///     Transform2D::scale(-2.0, 1.0).transform_rect(Rect::new(Point::new(1.0, 1.0), Size::new(2.0, 2.0))));
+    /// ```
+    pub fn transform_rect<U>(self, rect: Rect<T, U>) -> Rect<T, U> {
+        Rect::from_points(
+            [
+                rect.top_left(),
+                rect.top_right(),
+                rect.bottom_left(),
+                rect.bottom_right(),
+            ]
+            .iter()
+            .map(|&p| self.transform_point(p)),
+        )
+        .unwrap()
+    }
+}
+
+impl<T: Primitive + num::Float> Default for Transform2D<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
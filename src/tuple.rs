@@ -2,6 +2,7 @@ use crate::coord::Coord;
 
 impl<C: Coord> Coord for (C,) {
     type Item = (<C as Coord>::Item,);
+    type Unit = <C as Coord>::Unit;
 
     fn from_x_y(x: Self::Item, y: Self::Item) -> Self {
         (<C as Coord>::from_x_y(x.0, y.0),)
@@ -28,8 +29,9 @@ impl<C: Coord> Coord for (C,) {
     }
 }
 
-impl<C0: Coord, C1: Coord> Coord for (C0, C1) {
+impl<C0: Coord, C1: Coord<Unit = <C0 as Coord>::Unit>> Coord for (C0, C1) {
     type Item = (<C0 as Coord>::Item, <C1 as Coord>::Item);
+    type Unit = <C0 as Coord>::Unit;
 
     fn from_x_y(x: Self::Item, y: Self::Item) -> Self {
         (C0::from_x_y(x.0, y.0), C1::from_x_y(x.1, y.1))
@@ -58,12 +60,15 @@ impl<C0: Coord, C1: Coord> Coord for (C0, C1) {
     }
 }
 
-impl<C0: Coord, C1: Coord, C2: Coord> Coord for (C0, C1, C2) {
+impl<C0: Coord, C1: Coord<Unit = <C0 as Coord>::Unit>, C2: Coord<Unit = <C0 as Coord>::Unit>> Coord
+    for (C0, C1, C2)
+{
     type Item = (
         <C0 as Coord>::Item,
         <C1 as Coord>::Item,
         <C2 as Coord>::Item,
     );
+    type Unit = <C0 as Coord>::Unit;
 
     fn from_x_y(x: Self::Item, y: Self::Item) -> Self {
         (
@@ -0,0 +1,100 @@
+use crate::coord3::Coord3;
+
+impl<C: Coord3> Coord3 for (C,) {
+    type Item = (<C as Coord3>::Item,);
+    type Unit = <C as Coord3>::Unit;
+
+    fn from_x_y_z(x: Self::Item, y: Self::Item, z: Self::Item) -> Self {
+        (<C as Coord3>::from_x_y_z(x.0, y.0, z.0),)
+    }
+
+    fn x(self) -> Self::Item {
+        (self.0.x(),)
+    }
+
+    fn y(self) -> Self::Item {
+        (self.0.y(),)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Coord3;
+    /// # use coord::Point3;
+    /// assert_eq!((30,), (Point3::new(10, 20, 30),).z());
+    /// ```
+    fn z(self) -> Self::Item {
+        (self.0.z(),)
+    }
+}
+
+impl<C0: Coord3, C1: Coord3<Unit = <C0 as Coord3>::Unit>> Coord3 for (C0, C1) {
+    type Item = (<C0 as Coord3>::Item, <C1 as Coord3>::Item);
+    type Unit = <C0 as Coord3>::Unit;
+
+    fn from_x_y_z(x: Self::Item, y: Self::Item, z: Self::Item) -> Self {
+        (C0::from_x_y_z(x.0, y.0, z.0), C1::from_x_y_z(x.1, y.1, z.1))
+    }
+
+    fn x(self) -> Self::Item {
+        (self.0.x(), self.1.x())
+    }
+
+    fn y(self) -> Self::Item {
+        (self.0.y(), self.1.y())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Coord3;
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// assert_eq!((3, 30), (Vector3::new(1, 2, 3), Point3::new(10, 20, 30)).z());
+    /// ```
+    fn z(self) -> Self::Item {
+        (self.0.z(), self.1.z())
+    }
+}
+
+impl<
+        C0: Coord3,
+        C1: Coord3<Unit = <C0 as Coord3>::Unit>,
+        C2: Coord3<Unit = <C0 as Coord3>::Unit>,
+    > Coord3 for (C0, C1, C2)
+{
+    type Item = (
+        <C0 as Coord3>::Item,
+        <C1 as Coord3>::Item,
+        <C2 as Coord3>::Item,
+    );
+    type Unit = <C0 as Coord3>::Unit;
+
+    fn from_x_y_z(x: Self::Item, y: Self::Item, z: Self::Item) -> Self {
+        (
+            C0::from_x_y_z(x.0, y.0, z.0),
+            C1::from_x_y_z(x.1, y.1, z.1),
+            C2::from_x_y_z(x.2, y.2, z.2),
+        )
+    }
+
+    fn x(self) -> Self::Item {
+        (self.0.x(), self.1.x(), self.2.x())
+    }
+
+    fn y(self) -> Self::Item {
+        (self.0.y(), self.1.y(), self.2.y())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Coord3;
+    /// # use coord::Point3;
+    /// # use coord::Vector3;
+    /// # use coord::Size3;
+    /// assert_eq!(
+    ///     (3, 30, 300),
+    ///     (Vector3::new(1, 2, 3), Point3::new(10, 20, 30), Size3::new(100, 200, 300)).z());
+    /// ```
+    fn z(self) -> Self::Item {
+        (self.0.z(), self.1.z(), self.2.z())
+    }
+}
@@ -0,0 +1,82 @@
+/// The unit of coordinates which are not tagged with any particular space.
+///
+/// Any type, typically an empty enum, can be used as a unit to keep coordinates of different
+/// spaces apart, e.g. `Point<f32, Screen>` and `Point<f32, World>`.
+///
+/// # Examples
+/// ```
+/// # use coord::Point;
+/// # use coord::Vector;
+/// enum Screen {}
+/// let p: Point<i32, Screen> = Point::typed(3, 4);
+/// assert_eq!(Point::typed(4, 6), p + Vector::typed(1, 2));
+/// assert_eq!(Point::new(3, 4), p.to_untyped());
+/// ```
+///
+/// Coordinates of different units do not mix without an explicit `cast_unit`.
+/// ```compile_fail
+/// # use coord::Point;
+/// # use coord::Vector;
+/// enum Screen {}
+/// enum World {}
+/// let p: Point<i32, Screen> = Point::typed(3, 4);
+/// let v: Vector<i32, World> = Vector::typed(1, 2);
+/// let _ = p + v;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct UnknownUnit;
+
+/// Implements the traits which `derive` would implement only for units implementing them too.
+macro_rules! impl_unit_traits {
+    ($name:ident { $($field:ident),* }) => {
+        impl_unit_traits!($name<U> { $($field),* });
+    };
+    ($name:ident<const $n:ident, $($unit:ident),+> { $($field:ident),* }) => {
+        impl_unit_traits!(
+            @impl [crate::coord::Primitive] [const $n: usize, $($unit),+]
+            $name, $name<T, $n, $($unit),+> { $($field),* }
+        );
+    };
+    ($name:ident<T: $bound:path, $($unit:ident),+> { $($field:ident),* }) => {
+        impl_unit_traits!(
+            @impl [$bound] [$($unit),+] $name, $name<T, $($unit),+> { $($field),* }
+        );
+    };
+    ($name:ident<$($unit:ident),+> { $($field:ident),* }) => {
+        impl_unit_traits!(
+            @impl [crate::coord::Primitive] [$($unit),+] $name, $name<T, $($unit),+>
+            { $($field),* }
+        );
+    };
+    (@impl [$bound:path] [$($generics:tt)*] $name:ident, $ty:ty { $($field:ident),* }) => {
+        impl<T: $bound, $($generics)*> Clone for $ty {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T: $bound, $($generics)*> Copy for $ty {}
+
+        impl<T: $bound + PartialEq, $($generics)*> PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                true $(&& self.$field == other.$field)*
+            }
+        }
+
+        impl<T: $bound + Eq, $($generics)*> Eq for $ty {}
+
+        impl<T: $bound + std::hash::Hash, $($generics)*> std::hash::Hash for $ty {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $(self.$field.hash(state);)*
+            }
+        }
+
+        impl<T: $bound + std::fmt::Debug, $($generics)*> std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))*
+                    .finish()
+            }
+        }
+    };
+}
@@ -1,18 +1,32 @@
 use crate::coord::{Coord, Primitive};
 use crate::map::Map;
+use crate::unit::UnknownUnit;
 use num;
 use std::fmt;
+use std::marker::PhantomData;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Vector<T: Primitive> {
+pub struct Vector<T: Primitive, U = UnknownUnit> {
     x: T,
     y: T,
+    unit: PhantomData<U>,
 }
 
+impl_unit_traits!(Vector { x, y });
+
 impl<T: Primitive> Vector<T> {
     pub fn new(x: T, y: T) -> Self {
-        Self { x, y }
+        Self::typed(x, y)
+    }
+}
+
+impl<T: Primitive, U> Vector<T, U> {
+    pub fn typed(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+            unit: PhantomData,
+        }
     }
 
     /// # Examples
@@ -44,10 +58,36 @@ impl<T: Primitive> Vector<T> {
     {
         (self.x().powi(2) + self.y().powi(2)).sqrt()
     }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector;
+    /// enum Screen {}
+    /// let v: Vector<_, Screen> = Vector::new(3, 4).cast_unit();
+    /// assert_eq!(Vector::typed(3, 4), v);
+    /// ```
+    pub fn cast_unit<V>(self) -> Vector<T, V> {
+        Vector::typed(self.x(), self.y())
+    }
+
+    pub fn to_untyped(self) -> Vector<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(vector: Vector<T>) -> Self {
+        vector.cast_unit()
+    }
 }
 
-impl<T: Primitive> Coord for Vector<T> {
+impl<T: Primitive + Default, U> Default for Vector<T, U> {
+    fn default() -> Self {
+        Self::typed(T::default(), T::default())
+    }
+}
+
+impl<T: Primitive, U> Coord for Vector<T, U> {
     type Item = T;
+    type Unit = U;
 
     /// # Examples
     /// ```
@@ -56,7 +96,7 @@ impl<T: Primitive> Coord for Vector<T> {
     /// assert_eq!(Vector::new(10, 20), Vector::from_x_y(10, 20));
     /// ```
     fn from_x_y(x: T, y: T) -> Self {
-        Self::new(x, y)
+        Self::typed(x, y)
     }
 
     fn x(self) -> T {
@@ -68,30 +108,30 @@ impl<T: Primitive> Coord for Vector<T> {
     }
 }
 
-impl<T: Primitive + fmt::Display> fmt::Display for Vector<T> {
+impl<T: Primitive + fmt::Display, U> fmt::Display for Vector<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.x(), self.y())
     }
 }
 
-impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>> Add<Vector<RHSP>>
-    for Vector<T>
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, U> Add<Vector<RHSP, U>>
+    for Vector<T, U>
 {
-    type Output = Vector<<T as Add<RHSP>>::Output>;
+    type Output = Vector<<T as Add<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
     /// # use coord::Vector;
     /// assert_eq!(Vector::new(10, 15), Vector::new(3, 9) + Vector::new(7, 6));
     /// ```
-    fn add(self, rhs: Vector<RHSP>) -> Self::Output {
+    fn add(self, rhs: Vector<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(n, m)| n + m)
     }
 }
 
-impl<T: Primitive, RHS> AddAssign<RHS> for Vector<T>
+impl<T: Primitive, U, RHS> AddAssign<RHS> for Vector<T, U>
 where
-    Vector<T>: Add<RHS, Output = Self>,
+    Vector<T, U>: Add<RHS, Output = Self>,
 {
     /// # Examples
     /// ```
@@ -105,8 +145,8 @@ where
     }
 }
 
-impl<T: Primitive + Neg<Output = impl Primitive>> Neg for Vector<T> {
-    type Output = Vector<<T as Neg>::Output>;
+impl<T: Primitive + Neg<Output = impl Primitive>, U> Neg for Vector<T, U> {
+    type Output = Vector<<T as Neg>::Output, U>;
 
     /// # Examples
     /// ```
@@ -118,24 +158,24 @@ impl<T: Primitive + Neg<Output = impl Primitive>> Neg for Vector<T> {
     }
 }
 
-impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>> Sub<Vector<RHSP>>
-    for Vector<T>
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Vector<RHSP, U>>
+    for Vector<T, U>
 {
-    type Output = Vector<<T as Sub<RHSP>>::Output>;
+    type Output = Vector<<T as Sub<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
     /// # use coord::Vector;
     /// assert_eq!(Vector::new(3, 9), Vector::new(10, 15) - Vector::new(7, 6));
     /// ```
-    fn sub(self, rhs: Vector<RHSP>) -> Self::Output {
+    fn sub(self, rhs: Vector<RHSP, U>) -> Self::Output {
         (self, rhs).map(|(n, m)| n - m)
     }
 }
 
-impl<T: Primitive, RHS> SubAssign<RHS> for Vector<T>
+impl<T: Primitive, U, RHS> SubAssign<RHS> for Vector<T, U>
 where
-    Vector<T>: Sub<RHS, Output = Self>,
+    Vector<T, U>: Sub<RHS, Output = Self>,
 {
     /// # Examples
     /// ```
@@ -149,22 +189,22 @@ where
     }
 }
 
-impl<RHSP: Copy, T: Primitive + Mul<RHSP, Output = impl Primitive>> Mul<RHSP> for Vector<T> {
-    type Output = Vector<<T as Mul<RHSP>>::Output>;
+impl<T: Primitive + Mul<T, Output = impl Primitive>, U> Mul<T> for Vector<T, U> {
+    type Output = Vector<<T as Mul<T>>::Output, U>;
 
     /// # Examples
     /// ```
     /// # use coord::Vector;
     /// assert_eq!(Vector::new(20, 30), Vector::new(2, 3) * 10);
     /// ```
-    fn mul(self, rhs: RHSP) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         self.map(|n| n * rhs)
     }
 }
 
-impl<T: Primitive, RHS> MulAssign<RHS> for Vector<T>
+impl<T: Primitive, U, RHS> MulAssign<RHS> for Vector<T, U>
 where
-    Vector<T>: Mul<RHS, Output = Self>,
+    Vector<T, U>: Mul<RHS, Output = Self>,
 {
     /// # Examples
     /// ```
@@ -178,8 +218,10 @@ where
     }
 }
 
-impl<RHSP: Copy, T: Primitive + Div<RHSP, Output = impl Primitive>> Div<RHSP> for Vector<T> {
-    type Output = Vector<<T as Div<RHSP>>::Output>;
+impl<RHSP: Copy, T: Primitive + Div<RHSP, Output = impl Primitive>, U> Div<RHSP>
+    for Vector<T, U>
+{
+    type Output = Vector<<T as Div<RHSP>>::Output, U>;
 
     /// # Examples
     /// ```
@@ -191,9 +233,9 @@ impl<RHSP: Copy, T: Primitive + Div<RHSP, Output = impl Primitive>> Div<RHSP> fo
     }
 }
 
-impl<T: Primitive, RHS> DivAssign<RHS> for Vector<T>
+impl<T: Primitive, U, RHS> DivAssign<RHS> for Vector<T, U>
 where
-    Vector<T>: Div<RHS, Output = Self>,
+    Vector<T, U>: Div<RHS, Output = Self>,
 {
     /// # Examples
     /// ```
@@ -207,7 +249,7 @@ where
     }
 }
 
-impl<T: Primitive + num::Zero> num::Zero for Vector<T> {
+impl<T: Primitive + num::Zero, U> num::Zero for Vector<T, U> {
     /// # Examples
     /// ```
     /// # use coord::Vector;
@@ -215,7 +257,7 @@ impl<T: Primitive + num::Zero> num::Zero for Vector<T> {
     /// assert_eq!(Vector::new(0, 0), Vector::zero());
     /// ```
     fn zero() -> Self {
-        Self::new(T::zero(), T::zero())
+        Self::typed(T::zero(), T::zero())
     }
 
     /// # Examples
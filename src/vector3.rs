@@ -0,0 +1,273 @@
+use crate::coord::Primitive;
+use crate::coord3::Coord3;
+use crate::map3::Map3;
+use crate::unit::UnknownUnit;
+use num;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub struct Vector3<T: Primitive, U = UnknownUnit> {
+    x: T,
+    y: T,
+    z: T,
+    unit: PhantomData<U>,
+}
+
+impl_unit_traits!(Vector3 { x, y, z });
+
+impl<T: Primitive> Vector3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::typed(x, y, z)
+    }
+}
+
+impl<T: Primitive, U> Vector3<T, U> {
+    pub fn typed(x: T, y: T, z: T) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(3, Vector3::new(3, 4, 5).x());
+    /// ```
+    pub fn x(self) -> T {
+        self.x
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(4, Vector3::new(3, 4, 5).y());
+    /// ```
+    pub fn y(self) -> T {
+        self.y
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(5, Vector3::new(3, 4, 5).z());
+    /// ```
+    pub fn z(self) -> T {
+        self.z
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(7.0, Vector3::new(2.0, 3.0, 6.0).magnitude());
+    /// ```
+    pub fn magnitude(self) -> T
+    where
+        T: num::Float,
+    {
+        (self.x().powi(2) + self.y().powi(2) + self.z().powi(2)).sqrt()
+    }
+
+    pub fn cast_unit<V>(self) -> Vector3<T, V> {
+        Vector3::typed(self.x(), self.y(), self.z())
+    }
+
+    pub fn to_untyped(self) -> Vector3<T> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(vector: Vector3<T>) -> Self {
+        vector.cast_unit()
+    }
+}
+
+impl<T: Primitive + Default, U> Default for Vector3<T, U> {
+    fn default() -> Self {
+        Self::typed(T::default(), T::default(), T::default())
+    }
+}
+
+impl<T: Primitive, U> Coord3 for Vector3<T, U> {
+    type Item = T;
+    type Unit = U;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// # use coord::Coord3;
+    /// assert_eq!(Vector3::new(10, 20, 30), Vector3::from_x_y_z(10, 20, 30));
+    /// ```
+    fn from_x_y_z(x: T, y: T, z: T) -> Self {
+        Self::typed(x, y, z)
+    }
+
+    fn x(self) -> T {
+        self.x()
+    }
+
+    fn y(self) -> T {
+        self.y()
+    }
+
+    fn z(self) -> T {
+        self.z()
+    }
+}
+
+impl<T: Primitive + fmt::Display, U> fmt::Display for Vector3<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x(), self.y(), self.z())
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, U> Add<Vector3<RHSP, U>>
+    for Vector3<T, U>
+{
+    type Output = Vector3<<T as Add<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(Vector3::new(10, 15, 20), Vector3::new(3, 9, 1) + Vector3::new(7, 6, 19));
+    /// ```
+    fn add(self, rhs: Vector3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(n, m)| n + m)
+    }
+}
+
+impl<T: Primitive, U, RHS> AddAssign<RHS> for Vector3<T, U>
+where
+    Vector3<T, U>: Add<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// let mut v = Vector3::new(3, 9, 1);
+    /// v += Vector3::new(7, 6, 19);
+    /// assert_eq!(Vector3::new(10, 15, 20), v);
+    /// ```
+    fn add_assign(&mut self, rhs: RHS) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Primitive + Neg<Output = impl Primitive>, U> Neg for Vector3<T, U> {
+    type Output = Vector3<<T as Neg>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(Vector3::new(-5, -9, 2), -Vector3::new(5, 9, -2));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.map(|n| -n)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, U> Sub<Vector3<RHSP, U>>
+    for Vector3<T, U>
+{
+    type Output = Vector3<<T as Sub<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(Vector3::new(3, 9, 1), Vector3::new(10, 15, 20) - Vector3::new(7, 6, 19));
+    /// ```
+    fn sub(self, rhs: Vector3<RHSP, U>) -> Self::Output {
+        (self, rhs).map(|(n, m)| n - m)
+    }
+}
+
+impl<T: Primitive, U, RHS> SubAssign<RHS> for Vector3<T, U>
+where
+    Vector3<T, U>: Sub<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// let mut v = Vector3::new(10, 15, 20);
+    /// v -= Vector3::new(7, 6, 19);
+    /// assert_eq!(Vector3::new(3, 9, 1), v);
+    /// ```
+    fn sub_assign(&mut self, rhs: RHS) {
+        *self = *self - rhs;
+    }
+}
+
+impl<RHSP: Copy, T: Primitive + Mul<RHSP, Output = impl Primitive>, U> Mul<RHSP> for Vector3<T, U> {
+    type Output = Vector3<<T as Mul<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(Vector3::new(20, 30, 40), Vector3::new(2, 3, 4) * 10);
+    /// ```
+    fn mul(self, rhs: RHSP) -> Self::Output {
+        self.map(|n| n * rhs)
+    }
+}
+
+impl<T: Primitive, U, RHS> MulAssign<RHS> for Vector3<T, U>
+where
+    Vector3<T, U>: Mul<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// let mut v = Vector3::new(2, 3, 4);
+    /// v *= 10;
+    /// assert_eq!(Vector3::new(20, 30, 40), v);
+    /// ```
+    fn mul_assign(&mut self, rhs: RHS) {
+        *self = *self * rhs;
+    }
+}
+
+impl<RHSP: Copy, T: Primitive + Div<RHSP, Output = impl Primitive>, U> Div<RHSP> for Vector3<T, U> {
+    type Output = Vector3<<T as Div<RHSP>>::Output, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// assert_eq!(Vector3::new(6, 8, 1), Vector3::new(60, 80, 10) / 10);
+    /// ```
+    fn div(self, rhs: RHSP) -> Self::Output {
+        self.map(|n| n / rhs)
+    }
+}
+
+impl<T: Primitive, U, RHS> DivAssign<RHS> for Vector3<T, U>
+where
+    Vector3<T, U>: Div<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// let mut v = Vector3::new(60, 80, 10);
+    /// v /= 10;
+    /// assert_eq!(Vector3::new(6, 8, 1), v);
+    /// ```
+    fn div_assign(&mut self, rhs: RHS) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Primitive + num::Zero, U> num::Zero for Vector3<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::Vector3;
+    /// # use num::Zero;
+    /// assert_eq!(Vector3::new(0, 0, 0), Vector3::zero());
+    /// ```
+    fn zero() -> Self {
+        Self::typed(T::zero(), T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x().is_zero() && self.y().is_zero() && self.z().is_zero()
+    }
+}
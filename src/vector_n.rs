@@ -0,0 +1,292 @@
+use crate::coord::Primitive;
+use crate::coord_n::{CoordN, MapN};
+use crate::unit::UnknownUnit;
+use crate::vector::Vector;
+use num;
+use std::array;
+use std::marker::PhantomData;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// A vector of `N` components, which are indexed from 0 in the order x, y, z, and so on.
+pub struct VectorN<T: Primitive, const N: usize, U = UnknownUnit> {
+    components: [T; N],
+    unit: PhantomData<U>,
+}
+
+impl_unit_traits!(VectorN<const N, U> { components });
+
+impl<T: Primitive, const N: usize> VectorN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self::typed(components)
+    }
+}
+
+impl<T: Primitive, const N: usize, U> VectorN<T, N, U> {
+    pub fn typed(components: [T; N]) -> Self {
+        Self {
+            components,
+            unit: PhantomData,
+        }
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!([4, 8, 2, 6], VectorN::new([4, 8, 2, 6]).components());
+    /// ```
+    pub fn components(self) -> [T; N] {
+        self.components
+    }
+
+    pub fn cast_unit<V>(self) -> VectorN<T, N, V> {
+        VectorN::typed(self.components)
+    }
+
+    pub fn to_untyped(self) -> VectorN<T, N> {
+        self.cast_unit()
+    }
+
+    pub fn from_untyped(vector: VectorN<T, N>) -> Self {
+        vector.cast_unit()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(5.0, VectorN::new([1.0, 2.0, 2.0, 4.0]).magnitude());
+    /// ```
+    pub fn magnitude(self) -> T
+    where
+        T: num::Float,
+    {
+        self.components
+            .iter()
+            .fold(T::zero(), |sum, n| sum + n.powi(2))
+            .sqrt()
+    }
+}
+
+impl<T: Primitive + Default, const N: usize, U> Default for VectorN<T, N, U> {
+    fn default() -> Self {
+        Self::typed(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Primitive, const N: usize, U> CoordN<N> for VectorN<T, N, U> {
+    type Item = T;
+    type Unit = U;
+
+    fn from_array(components: [T; N]) -> Self {
+        Self::typed(components)
+    }
+
+    fn to_array(self) -> [T; N] {
+        self.components()
+    }
+}
+
+impl<T: Primitive, const N: usize, U> Index<usize> for VectorN<T, N, U> {
+    type Output = T;
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(2, VectorN::new([4, 8, 2, 6])[2]);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<T: Primitive, const N: usize, U> IndexMut<usize> for VectorN<T, N, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// let mut p = VectorN::new([4, 8, 2, 6]);
+    /// p[3] = 10;
+    /// assert_eq!(VectorN::new([4, 8, 2, 10]), p);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.components[index]
+    }
+}
+
+impl<T: Primitive, U> From<Vector<T, U>> for VectorN<T, 2, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// # use coord::Vector;
+    /// assert_eq!(VectorN::new([3, 4]), VectorN::from(Vector::new(3, 4)));
+    /// ```
+    fn from(vector: Vector<T, U>) -> Self {
+        Self::typed([vector.x(), vector.y()])
+    }
+}
+
+impl<T: Primitive, U> From<VectorN<T, 2, U>> for Vector<T, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// # use coord::Vector;
+    /// assert_eq!(Vector::new(3, 4), Vector::from(VectorN::new([3, 4])));
+    /// ```
+    fn from(vector: VectorN<T, 2, U>) -> Self {
+        let [x, y] = vector.components();
+        Vector::typed(x, y)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Add<RHSP, Output = impl Primitive>, const N: usize, U>
+    Add<VectorN<RHSP, N, U>> for VectorN<T, N, U>
+{
+    type Output = VectorN<<T as Add<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(VectorN::new([10, 15, 20, 15]), VectorN::new([3, 9, 1, -5]) + VectorN::new([7, 6, 19, 20]));
+    /// ```
+    fn add(self, rhs: VectorN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(n, m)| n + m)
+    }
+}
+
+impl<T: Primitive, RHS, const N: usize, U> AddAssign<RHS> for VectorN<T, N, U>
+where
+    VectorN<T, N, U>: Add<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// let mut v = VectorN::new([3, 9, 1, -5]);
+    /// v += VectorN::new([7, 6, 19, 20]);
+    /// assert_eq!(VectorN::new([10, 15, 20, 15]), v);
+    /// ```
+    fn add_assign(&mut self, rhs: RHS) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Primitive + Neg<Output = impl Primitive>, const N: usize, U> Neg for VectorN<T, N, U> {
+    type Output = VectorN<<T as Neg>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(VectorN::new([-5, -9, 2, 6]), -VectorN::new([5, 9, -2, -6]));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.map(|n| -n)
+    }
+}
+
+impl<RHSP: Primitive, T: Primitive + Sub<RHSP, Output = impl Primitive>, const N: usize, U>
+    Sub<VectorN<RHSP, N, U>> for VectorN<T, N, U>
+{
+    type Output = VectorN<<T as Sub<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(VectorN::new([3, 9, 1, -5]), VectorN::new([10, 15, 20, 15]) - VectorN::new([7, 6, 19, 20]));
+    /// ```
+    fn sub(self, rhs: VectorN<RHSP, N, U>) -> Self::Output {
+        (self, rhs).map(|(n, m)| n - m)
+    }
+}
+
+impl<T: Primitive, RHS, const N: usize, U> SubAssign<RHS> for VectorN<T, N, U>
+where
+    VectorN<T, N, U>: Sub<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// let mut v = VectorN::new([10, 15, 20, 15]);
+    /// v -= VectorN::new([7, 6, 19, 20]);
+    /// assert_eq!(VectorN::new([3, 9, 1, -5]), v);
+    /// ```
+    fn sub_assign(&mut self, rhs: RHS) {
+        *self = *self - rhs;
+    }
+}
+
+impl<RHSP: Copy, T: Primitive + Mul<RHSP, Output = impl Primitive>, const N: usize, U> Mul<RHSP>
+    for VectorN<T, N, U>
+{
+    type Output = VectorN<<T as Mul<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(VectorN::new([20, 30, 40, 30]), VectorN::new([2, 3, 4, 3]) * 10);
+    /// ```
+    fn mul(self, rhs: RHSP) -> Self::Output {
+        self.map(|n| n * rhs)
+    }
+}
+
+impl<T: Primitive, RHS, const N: usize, U> MulAssign<RHS> for VectorN<T, N, U>
+where
+    VectorN<T, N, U>: Mul<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// let mut v = VectorN::new([2, 3, 4, 3]);
+    /// v *= 10;
+    /// assert_eq!(VectorN::new([20, 30, 40, 30]), v);
+    /// ```
+    fn mul_assign(&mut self, rhs: RHS) {
+        *self = *self * rhs;
+    }
+}
+
+impl<RHSP: Copy, T: Primitive + Div<RHSP, Output = impl Primitive>, const N: usize, U> Div<RHSP>
+    for VectorN<T, N, U>
+{
+    type Output = VectorN<<T as Div<RHSP>>::Output, N, U>;
+
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// assert_eq!(VectorN::new([6, 8, 1, -1]), VectorN::new([60, 80, 10, -10]) / 10);
+    /// ```
+    fn div(self, rhs: RHSP) -> Self::Output {
+        self.map(|n| n / rhs)
+    }
+}
+
+impl<T: Primitive, RHS, const N: usize, U> DivAssign<RHS> for VectorN<T, N, U>
+where
+    VectorN<T, N, U>: Div<RHS, Output = Self>,
+{
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// let mut v = VectorN::new([60, 80, 10, -10]);
+    /// v /= 10;
+    /// assert_eq!(VectorN::new([6, 8, 1, -1]), v);
+    /// ```
+    fn div_assign(&mut self, rhs: RHS) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Primitive + num::Zero, const N: usize, U> num::Zero for VectorN<T, N, U> {
+    /// # Examples
+    /// ```
+    /// # use coord::VectorN;
+    /// # use num::Zero;
+    /// assert_eq!(VectorN::new([0, 0, 0, 0]), VectorN::zero());
+    /// ```
+    fn zero() -> Self {
+        Self::typed(array::from_fn(|_| T::zero()))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.components.iter().all(|n| n.is_zero())
+    }
+}
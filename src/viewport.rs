@@ -0,0 +1,220 @@
+use crate::angle::{Angle, AnglePrimitive};
+use crate::map::Map;
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::transform2d::Transform2D;
+use crate::unit::UnknownUnit;
+use crate::vector::Vector;
+
+/// A camera showing the world in `W` units around `center` on the `screen` rect in `S` units,
+/// magnified by `zoom` and rotated by `rotation`.
+pub struct Viewport<T: AnglePrimitive, W = UnknownUnit, S = UnknownUnit> {
+    center: Point<T, W>,
+    zoom: T,
+    rotation: Angle<T>,
+    screen: Rect<T, S>,
+    bounds: Option<Rect<T, W>>,
+}
+
+impl_unit_traits!(Viewport<T: AnglePrimitive, W, S> { center, zoom, rotation, screen, bounds });
+
+impl<T: AnglePrimitive, W, S> Viewport<T, W, S> {
+    /// Returns the viewport showing `center` at the center of `screen` without zoom or rotation.
+    pub fn new(center: Point<T, W>, screen: Rect<T, S>) -> Self {
+        Self {
+            center,
+            zoom: T::one(),
+            rotation: Angle::new(T::zero()),
+            screen,
+            bounds: None,
+        }
+    }
+
+    pub fn center(&self) -> Point<T, W> {
+        self.center
+    }
+
+    pub fn zoom(&self) -> T {
+        self.zoom
+    }
+
+    pub fn rotation(&self) -> Angle<T> {
+        self.rotation
+    }
+
+    pub fn screen(&self) -> Rect<T, S> {
+        self.screen
+    }
+
+    /// Returns the world rect the viewport is kept inside of, if any.
+    pub fn bounds(&self) -> Option<Rect<T, W>> {
+        self.bounds
+    }
+
+    pub fn set_center(&mut self, center: Point<T, W>) {
+        self.center = center;
+        self.clamp();
+    }
+
+    /// Sets the number of screen units per world unit.
+    pub fn set_zoom(&mut self, zoom: T) {
+        self.zoom = zoom;
+        self.clamp();
+    }
+
+    pub fn set_rotation(&mut self, rotation: Angle<T>) {
+        self.rotation = rotation;
+        self.clamp();
+    }
+
+    pub fn set_screen(&mut self, screen: Rect<T, S>) {
+        self.screen = screen;
+        self.clamp();
+    }
+
+    /// Keeps the visible world rect inside `bounds` from now on, centering the viewport on the
+    /// bounds along the axes on which the visible rect is larger than the bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Viewport;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut viewport = Viewport::new(Point::new(0.0, 0.0), Rect::new(Point::new(0.0, 0.0), Size::new(20.0, 10.0)));
+    /// viewport.set_bounds(Some(Rect::new(Point::new(0.0, 0.0), Size::new(100.0, 8.0))));
+    /// assert_eq!(Point::new(10.0, 4.0), viewport.center());
+    /// ```
+    pub fn set_bounds(&mut self, bounds: Option<Rect<T, W>>) {
+        self.bounds = bounds;
+        self.clamp();
+    }
+
+    /// Moves the center by `delta` in world units.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Viewport;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Vector;
+    /// let mut viewport = Viewport::new(Point::new(50.0, 50.0), Rect::new(Point::new(0.0, 0.0), Size::new(20.0, 10.0)));
+    /// viewport.set_bounds(Some(Rect::new(Point::new(0.0, 0.0), Size::new(100.0, 100.0))));
+    /// viewport.scroll_by(Vector::new(100.0, -10.0));
+    /// assert_eq!(Point::new(90.0, 40.0), viewport.center());
+    /// ```
+    pub fn scroll_by(&mut self, delta: Vector<T, W>) {
+        self.set_center(self.center + delta);
+    }
+
+    /// Centers the viewport on `target`.
+    pub fn follow(&mut self, target: Point<T, W>) {
+        self.set_center(target);
+    }
+
+    /// Scrolls the viewport just enough to keep `target` within `deadzone`, which is centered on
+    /// the screen.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Viewport;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut viewport = Viewport::new(Point::new(0.0, 0.0), Rect::new(Point::new(0.0, 0.0), Size::new(40.0, 20.0)));
+    /// viewport.set_zoom(2.0);
+    /// viewport.follow_with_deadzone(Point::new(3.0, 1.0), Size::new(10.0, 10.0));
+    /// assert_eq!(Point::new(0.5, 0.0), viewport.center());
+    /// ```
+    pub fn follow_with_deadzone(&mut self, target: Point<T, W>, deadzone: Size<T, S>) {
+        let inverse = match self.transform().inverse() {
+            Some(inverse) => inverse,
+            None => return,
+        };
+        let two = T::one() + T::one();
+        let offset = self.world_to_screen(target) - self.screen.center();
+        let excess: Vector<T, S> = (offset, deadzone).map(|(n, size)| {
+            let half = size / two;
+            n - n.max(-half).min(half)
+        });
+        self.set_center(self.center + inverse.transform_vector(excess).cast_unit());
+    }
+
+    /// Returns the transform mapping world coordinates to screen coordinates.
+    pub fn transform(&self) -> Transform2D<T> {
+        Transform2D::translation(Point::<T, W>::zero() - self.center)
+            .then_rotate(-self.rotation)
+            .then_scale(self.zoom, self.zoom)
+            .then_translate(self.screen.center() - Point::<T, S>::zero())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::Viewport;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut viewport = Viewport::new(Point::new(10.0, 10.0), Rect::new(Point::new(0.0, 0.0), Size::new(40.0, 20.0)));
+    /// viewport.set_zoom(2.0);
+    /// assert_eq!(Point::new(24.0, 6.0), viewport.world_to_screen(Point::new(12.0, 8.0)));
+    /// ```
+    pub fn world_to_screen(&self, point: Point<T, W>) -> Point<T, S> {
+        self.transform().transform_point(point).cast_unit()
+    }
+
+    /// Returns the world point shown at `point` on the screen, or `None` if the zoom is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Viewport;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut viewport = Viewport::new(Point::new(10.0, 10.0), Rect::new(Point::new(0.0, 0.0), Size::new(40.0, 20.0)));
+    /// viewport.set_zoom(2.0);
+    /// assert_eq!(Some(Point::new(12.0, 8.0)), viewport.screen_to_world(Point::new(24.0, 6.0)));
+    /// ```
+    pub fn screen_to_world(&self, point: Point<T, S>) -> Option<Point<T, W>> {
+        let inverse = self.transform().inverse()?;
+        Some(inverse.transform_point(point).cast_unit())
+    }
+
+    /// Returns the bounding rect of the world area shown on the screen.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Viewport;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let mut viewport = Viewport::new(Point::new(10.0, 10.0), Rect::new(Point::new(0.0, 0.0), Size::new(40.0, 20.0)));
+    /// viewport.set_zoom(2.0);
+    /// assert_eq!(Some(Rect::new(Point::new(0.0, 5.0), Size::new(20.0, 10.0))), viewport.visible_world_rect());
+    /// ```
+    pub fn visible_world_rect(&self) -> Option<Rect<T, W>> {
+        let inverse = self.transform().inverse()?;
+        Some(inverse.transform_rect(self.screen).cast_unit())
+    }
+
+    fn clamp(&mut self) {
+        let (bounds, visible) = match (self.bounds, self.visible_world_rect()) {
+            (Some(bounds), Some(visible)) => (bounds, visible),
+            _ => return,
+        };
+        let two = T::one() + T::one();
+        let shift: Vector<T, W> = (visible, bounds).map(|((v, v_len), (b, b_len))| {
+            if b_len <= v_len {
+                (b + b_len / two) - (v + v_len / two)
+            } else if v < b {
+                b - v
+            } else if b + b_len < v + v_len {
+                (b + b_len) - (v + v_len)
+            } else {
+                T::zero()
+            }
+        });
+        self.center += shift;
+    }
+}
@@ -0,0 +1,397 @@
+use crate::chunk::rem_euclid;
+use crate::coord::Primitive;
+use crate::direction::{Direction4, Direction8};
+use crate::point::Point;
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::vector::Vector;
+use num;
+
+/// A space of `size` from the origin whose opposite edges are joined along the wrapping axes,
+/// such as a world map wrapping horizontally or a torus wrapping on both axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrappedSpace<T: Primitive> {
+    size: Size<T>,
+    wrap_x: bool,
+    wrap_y: bool,
+}
+
+/// Returns the start and length of the parts of the range from `start` of `length` within
+/// `0..size`, splitting it at the seam if `wrap`, or clipping it otherwise.
+fn split_range<T: Primitive + num::Integer>(
+    start: T,
+    length: T,
+    size: T,
+    wrap: bool,
+) -> Vec<(T, T)> {
+    if wrap {
+        let length = if size < length { size } else { length };
+        let start = rem_euclid(start, size);
+        if start + length <= size {
+            vec![(start, length)]
+        } else {
+            vec![(start, size - start), (T::zero(), length - (size - start))]
+        }
+    } else {
+        let end = start + length;
+        let start = if start < T::zero() { T::zero() } else { start };
+        let end = if size < end { size } else { end };
+        if start < end {
+            vec![(start, end - start)]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl<T: Primitive> WrappedSpace<T> {
+    /// # Panics
+    /// Panics if `size` is not positive along a wrapping axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::new(Size::new(100, 0), true, false);
+    /// assert!(space.wraps_x());
+    /// assert!(!space.wraps_y());
+    /// ```
+    pub fn new(size: Size<T>, wrap_x: bool, wrap_y: bool) -> Self
+    where
+        T: num::Zero + PartialOrd,
+    {
+        assert!(
+            (!wrap_x || T::zero() < size.width()) && (!wrap_y || T::zero() < size.height()),
+            "size must be positive along the wrapping axes"
+        );
+        Self {
+            size,
+            wrap_x,
+            wrap_y,
+        }
+    }
+
+    /// Returns the space wrapping on both axes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::torus(Size::new(100, 50));
+    /// assert!(space.wraps_x() && space.wraps_y());
+    /// ```
+    pub fn torus(size: Size<T>) -> Self
+    where
+        T: num::Zero + PartialOrd,
+    {
+        Self::new(size, true, true)
+    }
+
+    /// Returns the space wrapping only along x, like a cylindrical world map.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::horizontal(Size::new(100, 50));
+    /// assert!(space.wraps_x() && !space.wraps_y());
+    /// ```
+    pub fn horizontal(size: Size<T>) -> Self
+    where
+        T: num::Zero + PartialOrd,
+    {
+        Self::new(size, true, false)
+    }
+
+    /// Returns the space wrapping only along y.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::vertical(Size::new(100, 50));
+    /// assert!(!space.wraps_x() && space.wraps_y());
+    /// ```
+    pub fn vertical(size: Size<T>) -> Self
+    where
+        T: num::Zero + PartialOrd,
+    {
+        Self::new(size, false, true)
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// assert_eq!(Size::new(100, 50), WrappedSpace::torus(Size::new(100, 50)).size());
+    /// ```
+    pub fn size(&self) -> Size<T> {
+        self.size
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// assert!(WrappedSpace::horizontal(Size::new(100, 50)).wraps_x());
+    /// assert!(!WrappedSpace::vertical(Size::new(100, 50)).wraps_x());
+    /// ```
+    pub fn wraps_x(&self) -> bool {
+        self.wrap_x
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Size;
+    /// assert!(WrappedSpace::vertical(Size::new(100, 50)).wraps_y());
+    /// assert!(!WrappedSpace::horizontal(Size::new(100, 50)).wraps_y());
+    /// ```
+    pub fn wraps_y(&self) -> bool {
+        self.wrap_y
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(0, 0), Size::new(100, 50)),
+    ///     WrappedSpace::torus(Size::new(100, 50)).bounds());
+    /// ```
+    pub fn bounds(&self) -> Rect<T>
+    where
+        T: num::Zero,
+    {
+        Rect::new(Point::zero(), self.size)
+    }
+}
+
+impl<T: Primitive + num::Integer> WrappedSpace<T> {
+    /// Wraps `point` into the bounds along the wrapping axes, leaving the other components as
+    /// they are.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::horizontal(Size::new(100, 50));
+    /// assert_eq!(Point::new(98, 60), space.normalize(Point::new(-2, 60)));
+    /// assert_eq!(Point::new(3, 7), space.normalize(Point::new(203, 7)));
+    /// let strip = WrappedSpace::horizontal(Size::new(100, 0));
+    /// assert_eq!(Point::new(98, 3), strip.normalize(Point::new(-2, 3)));
+    /// ```
+    pub fn normalize(&self, point: Point<T>) -> Point<T> {
+        let wrap = |n: T, size: T, wrap: bool| if wrap { rem_euclid(n, size) } else { n };
+        Point::new(
+            wrap(point.x(), self.size.width(), self.wrap_x),
+            wrap(point.y(), self.size.height(), self.wrap_y),
+        )
+    }
+
+    /// Returns whether `point` is within the bounds along the axes which do not wrap, i.e.
+    /// whether it is anywhere in the space once normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::horizontal(Size::new(100, 50));
+    /// assert!(space.contains(Point::new(-2, 49)));
+    /// assert!(!space.contains(Point::new(-2, 50)));
+    /// ```
+    pub fn contains(&self, point: Point<T>) -> bool {
+        let inside = |n: T, size: T, wrap: bool| wrap || (T::zero() <= n && n < size);
+        inside(point.x(), self.size.width(), self.wrap_x)
+            && inside(point.y(), self.size.height(), self.wrap_y)
+    }
+
+    /// Returns the shortest vector from `from` to `to`, going across the seams if shorter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// # use coord::Vector;
+    /// let space = WrappedSpace::torus(Size::new(100, 50));
+    /// assert_eq!(Vector::new(-5, 20), space.delta(Point::new(2, 10), Point::new(97, 30)));
+    /// assert_eq!(Vector::new(5, -20), space.delta(Point::new(97, 30), Point::new(2, 10)));
+    /// ```
+    pub fn delta(&self, from: Point<T>, to: Point<T>) -> Vector<T>
+    where
+        T: num::Signed,
+    {
+        let wrap = |d: T, size: T, wrap: bool| {
+            if !wrap {
+                return d;
+            }
+            let d = rem_euclid(d, size);
+            if size < d + d {
+                d - size
+            } else {
+                d
+            }
+        };
+        let d = to - from;
+        Vector::new(
+            wrap(d.x(), self.size.width(), self.wrap_x),
+            wrap(d.y(), self.size.height(), self.wrap_y),
+        )
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::torus(Size::new(100, 50));
+    /// assert_eq!(25, space.manhattan_distance(Point::new(2, 10), Point::new(97, 30)));
+    /// ```
+    pub fn manhattan_distance(&self, from: Point<T>, to: Point<T>) -> T
+    where
+        T: num::Signed,
+    {
+        let d = self.delta(from, to);
+        d.x().abs() + d.y().abs()
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::torus(Size::new(100, 50));
+    /// assert_eq!(20, space.chebyshev_distance(Point::new(2, 10), Point::new(97, 30)));
+    /// ```
+    pub fn chebyshev_distance(&self, from: Point<T>, to: Point<T>) -> T
+    where
+        T: num::Signed,
+    {
+        let d = self.delta(from, to);
+        d.x().abs().max(d.y().abs())
+    }
+
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::torus(Size::new(100, 50));
+    /// assert_eq!(425, space.distance_squared(Point::new(2, 10), Point::new(97, 30)));
+    /// ```
+    pub fn distance_squared(&self, from: Point<T>, to: Point<T>) -> T
+    where
+        T: num::Signed,
+    {
+        let d = self.delta(from, to);
+        d.x() * d.x() + d.y() * d.y()
+    }
+
+    /// Iterates the normalized neighbors of `point` in the order of `Direction4::ALL`, skipping
+    /// those falling off the axes which do not wrap as well as the point itself and repeated
+    /// neighbors on axes shorter than 3.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::horizontal(Size::new(10, 5));
+    /// assert_eq!(
+    ///     vec![Point::new(1, 0), Point::new(0, 1), Point::new(9, 0)],
+    ///     space.neighbors4(Point::new(0, 0)).collect::<Vec<_>>());
+    /// ```
+    pub fn neighbors4(&self, point: Point<T>) -> impl Iterator<Item = Point<T>>
+    where
+        T: num::Signed,
+    {
+        self.neighbors(
+            point,
+            Direction4::iter().map(|direction| direction.to_vector()),
+        )
+    }
+
+    /// Iterates the normalized neighbors of `point` in the order of `Direction8::ALL`, skipping
+    /// those falling off the axes which do not wrap as well as the point itself and repeated
+    /// neighbors on axes shorter than 3.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::torus(Size::new(10, 5));
+    /// assert_eq!(Some(Point::new(1, 4)), space.neighbors8(Point::new(0, 0)).nth(1));
+    /// assert_eq!(8, space.neighbors8(Point::new(0, 0)).count());
+    /// ```
+    pub fn neighbors8(&self, point: Point<T>) -> impl Iterator<Item = Point<T>>
+    where
+        T: num::Signed,
+    {
+        self.neighbors(
+            point,
+            Direction8::iter().map(|direction| direction.to_vector()),
+        )
+    }
+
+    fn neighbors(
+        &self,
+        point: Point<T>,
+        vectors: impl Iterator<Item = Vector<T>>,
+    ) -> impl Iterator<Item = Point<T>> {
+        let origin = self.normalize(point);
+        let mut neighbors = Vec::new();
+        for vector in vectors {
+            let next = point + vector;
+            if self.contains(next) {
+                let next = self.normalize(next);
+                if next != origin && !neighbors.contains(&next) {
+                    neighbors.push(next);
+                }
+            }
+        }
+        neighbors.into_iter()
+    }
+
+    /// Splits `rect` at the seams into up to four rects within the bounds showing the same cells.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::WrappedSpace;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let space = WrappedSpace::horizontal(Size::new(100, 50));
+    /// assert_eq!(
+    ///     vec![
+    ///         Rect::new(Point::new(95, 40), Size::new(5, 10)),
+    ///         Rect::new(Point::new(0, 40), Size::new(15, 10)),
+    ///     ],
+    ///     space.split_rect(Rect::new(Point::new(-5, 40), Size::new(20, 20))));
+    /// ```
+    pub fn split_rect(&self, rect: Rect<T>) -> Vec<Rect<T>> {
+        let xs = split_range(
+            rect.left(),
+            rect.size().width(),
+            self.size.width(),
+            self.wrap_x,
+        );
+        let ys = split_range(
+            rect.top(),
+            rect.size().height(),
+            self.size.height(),
+            self.wrap_y,
+        );
+        ys.iter()
+            .flat_map(|&(y, height)| {
+                xs.iter()
+                    .map(move |&(x, width)| Rect::new(Point::new(x, y), Size::new(width, height)))
+            })
+            .collect()
+    }
+}
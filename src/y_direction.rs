@@ -0,0 +1,104 @@
+use crate::coord::Primitive;
+use crate::point::Point;
+use crate::rect::Rect;
+use std::ops::{Add, Sub};
+
+/// The direction in which y grows on the screen.
+///
+/// The crate itself is y-down like most screen and image coordinates: `Rect::top()` is the
+/// minimum y, `Rect::bottom()` is the maximum y, and points are iterated row by row from the
+/// minimum y. Coordinates of y-up systems such as OpenGL or PDF keep working unchanged, but
+/// their visually upper edge is `Rect::bottom()`, which `YDirection::Up` answers for you.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YDirection {
+    #[default]
+    Down,
+    Up,
+}
+
+impl YDirection {
+    /// # Examples
+    /// ```
+    /// # use coord::YDirection;
+    /// assert_eq!(YDirection::Up, YDirection::Down.flip());
+    /// ```
+    pub fn flip(self) -> Self {
+        match self {
+            YDirection::Down => YDirection::Up,
+            YDirection::Up => YDirection::Down,
+        }
+    }
+
+    /// Returns the y of the visually upper edge of `rect`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::YDirection;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(3, 4), Size::new(10, 20));
+    /// assert_eq!(4, YDirection::Down.top(rect));
+    /// assert_eq!(24, YDirection::Up.top(rect));
+    /// ```
+    pub fn top<T: Primitive + Add<T, Output = T>, U>(self, rect: Rect<T, U>) -> T {
+        match self {
+            YDirection::Down => rect.top(),
+            YDirection::Up => rect.bottom(),
+        }
+    }
+
+    /// Returns the y of the visually lower edge of `rect`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::YDirection;
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// let rect = Rect::new(Point::new(3, 4), Size::new(10, 20));
+    /// assert_eq!(24, YDirection::Down.bottom(rect));
+    /// assert_eq!(4, YDirection::Up.bottom(rect));
+    /// ```
+    pub fn bottom<T: Primitive + Add<T, Output = T>, U>(self, rect: Rect<T, U>) -> T {
+        self.flip().top(rect)
+    }
+}
+
+impl<T: Primitive + Sub<T, Output = T>, U> Point<T, U> {
+    /// Mirrors the point vertically within a space of `height`, converting between y-down and
+    /// y-up coordinates.
+    ///
+    /// This flips positions; flip the cell indices of a grid with `height - 1` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Point;
+    /// assert_eq!(Point::new(3, 16), Point::new(3, 4).flip_y(20));
+    /// assert_eq!(Point::new(3, 4), Point::new(3, 4).flip_y(20).flip_y(20));
+    /// ```
+    pub fn flip_y(self, height: T) -> Self {
+        Point::typed(self.x(), height - self.y())
+    }
+}
+
+impl<T: Primitive + Add<T, Output = T> + Sub<T, Output = T>, U> Rect<T, U> {
+    /// Mirrors the rect vertically within a space of `height`, so that its top edge in one
+    /// convention becomes its top edge in the other.
+    ///
+    /// # Examples
+    /// ```
+    /// # use coord::Rect;
+    /// # use coord::Point;
+    /// # use coord::Size;
+    /// assert_eq!(
+    ///     Rect::new(Point::new(3, 76), Size::new(10, 20)),
+    ///     Rect::new(Point::new(3, 4), Size::new(10, 20)).flip_y(100));
+    /// ```
+    pub fn flip_y(self, height: T) -> Self {
+        Rect::typed(
+            Point::typed(self.left(), height - self.bottom()),
+            self.size(),
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use coord::prelude::*;
+
+fn points(size: [i32; 3]) -> Vec<PointN<i32, 3>> {
+    BoxN::new(PointN::new([0, 0, 0]), SizeN::new(size))
+        .points()
+        .collect()
+}
+
+#[test]
+fn empty_first_dimension() {
+    assert_eq!(Vec::<PointN<i32, 3>>::new(), points([0, 2, 3]));
+}
+
+#[test]
+fn empty_middle_dimension() {
+    assert_eq!(Vec::<PointN<i32, 3>>::new(), points([2, 0, 3]));
+}
+
+#[test]
+fn empty_last_dimension() {
+    assert_eq!(Vec::<PointN<i32, 3>>::new(), points([2, 3, 0]));
+}
+
+#[test]
+fn reversed_inclusive_range_is_empty() {
+    let mut points = PointNRangeIteratorInclusive::new(
+        PointN::new([0, 1, 0]),
+        PointN::new([2, 0, 3]),
+        VectorN::new([1, 1, 1]),
+    );
+    assert_eq!(None, points.next());
+}
@@ -0,0 +1,107 @@
+use coord::prelude::*;
+
+fn lcg(seed: u64) -> impl FnMut() -> f64 {
+    let mut state = seed;
+    move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[test]
+fn leaves_tile_root() {
+    let rect = Rect::new(Point::new(0, 0), Size::new(80, 50));
+    let bsp = BspSplitter::new(Size::new(6, 6)).build_with_rng(rect, lcg(42));
+    let leaves = bsp.leaf_rects().collect::<Vec<_>>();
+    assert!(leaves.len() > 1);
+    assert_eq!(
+        rect.size().area(),
+        leaves.iter().map(|r| r.size().area()).sum::<i32>()
+    );
+    for p in rect.points() {
+        assert_eq!(1, leaves.iter().filter(|r| r.contains(p)).count());
+    }
+}
+
+#[test]
+fn leaves_respect_min_size() {
+    let rect = Rect::new(Point::new(0, 0), Size::new(80, 50));
+    let bsp = BspSplitter::new(Size::new(6, 4))
+        .ratio(0.3, 0.7)
+        .build_with_rng(rect, lcg(7));
+    for leaf in bsp.leaf_rects() {
+        assert!(6 <= leaf.size().width() && 4 <= leaf.size().height());
+    }
+}
+
+#[test]
+fn children_split_parent() {
+    let rect = Rect::new(Point::new(0, 0), Size::new(64, 64));
+    let bsp = BspSplitter::new(Size::new(4, 4)).build_with_rng(rect, lcg(1));
+    for (id, node) in bsp.nodes().iter().enumerate() {
+        if let Some((first, second)) = node.children() {
+            assert_eq!(Some(id), bsp.node(first).parent());
+            assert_eq!(Some(second), bsp.sibling(first));
+            assert_eq!(
+                node.rect().size().area(),
+                bsp.node(first).rect().size().area() + bsp.node(second).rect().size().area()
+            );
+            assert_eq!(node.depth() + 1, bsp.node(first).depth());
+        }
+    }
+}
+
+#[test]
+fn falls_back_to_other_axis() {
+    let rect = Rect::new(Point::new(0, 0), Size::new(20, 4));
+    let bsp = BspSplitter::new(Size::new(4, 4))
+        .max_depth(1)
+        .build(rect, |_, _| Some((Axis::Y, 0.5)));
+    assert_eq!(
+        vec![
+            Rect::new(Point::new(0, 0), Size::new(10, 4)),
+            Rect::new(Point::new(10, 0), Size::new(10, 4))
+        ],
+        bsp.leaf_rects().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn too_small_to_split() {
+    let rect = Rect::new(Point::new(0, 0), Size::new(7, 7));
+    let bsp = BspSplitter::new(Size::new(4, 4)).build(rect, |_, _| Some((Axis::X, 0.5)));
+    assert_eq!(1, bsp.nodes().len());
+    assert!(bsp.node(bsp.root()).is_leaf());
+}
+
+#[test]
+fn build_with_rng_terminates_at_extremes() {
+    let rect = Rect::new(Point::new(0, 0), Size::new(33, 17));
+    for &n in &[0.0, 0.999_999] {
+        let bsp = BspSplitter::new(Size::new(1, 1)).build_with_rng(rect, || n);
+        assert_eq!(rect.size().area(), bsp.leaves().count() as i32);
+    }
+}
+
+#[test]
+fn build_with_rng_terminates_for_floats() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Size::new(10.0, 6.0));
+    let bsp = BspSplitter::new(Size::new(0.5, 0.5)).build_with_rng(rect, lcg(3));
+    for leaf in bsp.leaf_rects() {
+        assert!(0.5 <= leaf.size().width() && 0.5 <= leaf.size().height());
+    }
+}
+
+#[test]
+#[should_panic]
+fn zero_min_size() {
+    BspSplitter::new(Size::new(0, 4));
+}
+
+#[test]
+#[should_panic]
+fn inverted_ratio() {
+    BspSplitter::new(Size::new(4, 4)).ratio(0.7, 0.3);
+}
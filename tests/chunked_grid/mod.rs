@@ -0,0 +1,80 @@
+use coord::prelude::*;
+
+#[test]
+fn negative_points_map_to_their_own_chunks() {
+    let mut grid = ChunkedGrid::new(Size::new(4, 3), 0);
+    grid.insert(Point::new(-1, -1), 1);
+    assert_eq!(vec![Point::new(-1, -1)], grid.chunks().collect::<Vec<_>>());
+    assert_eq!(
+        Some(Rect::new(Point::new(-4, -3), Size::new(4, 3))),
+        grid.bounds()
+    );
+    assert_eq!(None, grid.get(Point::new(0, 0)));
+    assert_eq!(Some(&0), grid.get(Point::new(-4, -3)));
+    assert_eq!(None, grid.get(Point::new(-5, -3)));
+}
+
+#[test]
+fn agrees_with_sparse_grid() {
+    let points = (-9..9)
+        .flat_map(|y| (-9..9).map(move |x| Point::new(x * 7 % 11, y * 5 % 13)))
+        .collect::<Vec<_>>();
+    let mut chunked = ChunkedGrid::new(Size::new(5, 4), None);
+    let mut sparse = SparseGrid::new();
+    for (i, &p) in points.iter().enumerate() {
+        assert_eq!(sparse.insert(p, i), chunked.insert(p, Some(i)).flatten());
+    }
+    for (p, &i) in sparse.iter() {
+        assert_eq!(Some(&Some(i)), chunked.get(p));
+    }
+    assert_eq!(
+        sparse.len(),
+        chunked.iter().filter(|(_, cell)| cell.is_some()).count()
+    );
+    let bounds = chunked.bounds().unwrap();
+    assert!(bounds.contains_rect(sparse.bounds().unwrap()));
+    for (p, _) in chunked.iter() {
+        assert!(bounds.contains(p));
+    }
+}
+
+#[test]
+fn chunk_decomposition_round_trips() {
+    let chunk_size = Size::new(4, 3);
+    for p in Rect::new(Point::new(-9, -7), Size::new(18, 14)).points() {
+        let (chunk, local) = p.to_chunk(chunk_size);
+        assert!(0 <= local.x() && local.x() < 4 && 0 <= local.y() && local.y() < 3);
+        assert_eq!(p, Point::from_chunk(chunk, local, chunk_size));
+    }
+}
+
+#[test]
+fn chunks_overlapping_cover_exactly_the_touched_chunks() {
+    let chunk_size = Size::new(4, 3);
+    let rect = Rect::new(Point::new(-5, -1), Size::new(7, 5));
+    let mut touched = rect
+        .points()
+        .map(|p| p.div_euclid(chunk_size))
+        .collect::<Vec<_>>();
+    touched.sort_by_key(|p| (p.y(), p.x()));
+    touched.dedup();
+    assert_eq!(
+        touched,
+        rect.chunks_overlapping(chunk_size).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn euclid_division_agrees_with_std_at_extremes() {
+    for &n in &[i32::MIN, i32::MIN + 1, -7, -1, 0, 7, i32::MAX] {
+        for &m in &[i32::MIN, -3, -1, 1, 3, i32::MAX] {
+            if n == i32::MIN && m == -1 {
+                continue;
+            }
+            let p = Point::new(n, 0);
+            let size = Size::new(m, 1);
+            assert_eq!(n.div_euclid(m), p.div_euclid(size).x());
+            assert_eq!(n.rem_euclid(m), p.rem_euclid(size).x());
+        }
+    }
+}
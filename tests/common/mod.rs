@@ -0,0 +1,10 @@
+use coord::prelude::*;
+
+pub fn assert_close(expected: Point<f64>, actual: Point<f64>) {
+    assert!(
+        (expected.x() - actual.x()).abs() < 1e-9 && (expected.y() - actual.y()).abs() < 1e-9,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
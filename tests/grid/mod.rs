@@ -0,0 +1,106 @@
+use coord::prelude::*;
+
+fn numbered(size: Size<usize>) -> Grid<usize> {
+    Grid::from_fn(size, |p| p.y() * size.width() + p.x())
+}
+
+#[test]
+fn rotates_square_in_place_like_rotated_cw() {
+    for n in 0..6 {
+        let mut grid = numbered(Size::new(n, n));
+        let rotated = grid.rotated_cw();
+        grid.rotate_square_cw();
+        assert_eq!(rotated, grid);
+    }
+}
+
+#[test]
+fn rotating_four_times_is_identity() {
+    let grid = numbered(Size::new(5, 3));
+    assert_eq!(
+        grid,
+        grid.rotated_cw().rotated_cw().rotated_cw().rotated_cw()
+    );
+    assert_eq!(grid, grid.rotated_cw().rotated_ccw());
+}
+
+#[test]
+fn transforms_follow_symmetry_composition() {
+    let grid = numbered(Size::new(4, 3));
+    for &a in Symmetry::ALL.iter() {
+        assert_eq!(grid, grid.transformed(a).transformed(a.inverse()));
+        for &b in Symmetry::ALL.iter() {
+            assert_eq!(
+                grid.transformed(a.then(b)),
+                grid.transformed(a).transformed(b)
+            );
+        }
+    }
+}
+
+#[test]
+fn transformed_cells_move_with_symmetry() {
+    let grid = numbered(Size::new(4, 3));
+    for &symmetry in Symmetry::ALL.iter() {
+        let transformed = grid.transformed(symmetry);
+        for (p, cell) in grid.iter() {
+            assert_eq!(cell, &transformed[symmetry.transform_cell(p, grid.size())]);
+        }
+    }
+}
+
+#[test]
+fn views_outside_the_grid_are_empty() {
+    let grid = numbered(Size::new(4, 3));
+    let view = grid.view(Rect::new(Point::new(6, 1), Size::new(2, 2)));
+    assert_eq!(Size::new(0, 0), view.size());
+    assert_eq!(0, view.iter().count());
+    assert_eq!(None, view.get(Point::new(0, 0)));
+}
+
+#[test]
+fn nested_mutable_views_write_through() {
+    let mut grid = Grid::new(Size::new(6, 5), 0);
+    {
+        let mut outer = grid.view_mut(Rect::new(Point::new(1, 1), Size::new(4, 3)));
+        let mut inner = outer.view_mut(Rect::new(Point::new(2, 1), Size::new(5, 5)));
+        assert_eq!(Rect::new(Point::new(3, 2), Size::new(2, 2)), inner.rect());
+        for (p, cell) in inner.iter_mut() {
+            *cell = 1 + p.x() + p.y() * 2;
+        }
+    }
+    let written = grid
+        .iter()
+        .filter(|&(_, &n)| n != 0)
+        .map(|(p, &n)| (p, n))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (Point::new(3, 2), 1),
+            (Point::new(4, 2), 2),
+            (Point::new(3, 3), 3),
+            (Point::new(4, 3), 4),
+        ],
+        written
+    );
+}
+
+#[test]
+fn blit_clips_to_destination_view() {
+    let stamp = numbered(Size::new(3, 3));
+    let mut grid = Grid::new(Size::new(5, 5), 100);
+    grid.view_mut(Rect::new(Point::new(1, 1), Size::new(3, 3)))
+        .blit(stamp.view(stamp.bounds()), Point::new(1, 2));
+    let changed = grid
+        .iter()
+        .filter(|&(_, &n)| n != 100)
+        .map(|(p, &n)| (p, n))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![(Point::new(2, 3), 0), (Point::new(3, 3), 1)], changed);
+}
+
+#[test]
+#[should_panic(expected = "number of cells must not overflow")]
+fn overflowing_size() {
+    Grid::new(Size::new(usize::MAX, 2), 0u8);
+}
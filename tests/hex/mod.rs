@@ -0,0 +1,69 @@
+use coord::prelude::*;
+
+#[test]
+fn ring_hexes_are_at_radius_and_adjacent() {
+    let center = Hex::new(2, -5);
+    for radius in 1..5 {
+        let ring = center.ring(radius).collect::<Vec<_>>();
+        assert_eq!(radius * 6, ring.len());
+        for (i, &hex) in ring.iter().enumerate() {
+            assert_eq!(radius as i32, center.distance(hex));
+            assert_eq!(1, hex.distance(ring[(i + 1) % ring.len()]));
+        }
+    }
+}
+
+#[test]
+fn spiral_covers_every_hex_within_radius_once() {
+    let center = Hex::new(-1, 3);
+    let mut hexes = center.spiral(3).collect::<Vec<_>>();
+    assert_eq!(37, hexes.len());
+    hexes.sort_by_key(|hex| (hex.q(), hex.r()));
+    hexes.dedup();
+    assert_eq!(37, hexes.len());
+    assert!(hexes.iter().all(|&hex| center.distance(hex) <= 3));
+}
+
+#[test]
+fn line_steps_between_neighbors() {
+    let (from, to) = (Hex::new(-4, 1), Hex::new(3, -6));
+    let line = from.line_to(to).collect::<Vec<_>>();
+    assert_eq!(from.distance(to) as usize + 1, line.len());
+    assert_eq!(Some(&from), line.first());
+    assert_eq!(Some(&to), line.last());
+    assert!(line.windows(2).all(|pair| pair[0].distance(pair[1]) == 1));
+}
+
+#[test]
+fn rotating_six_times_is_identity() {
+    let center = Hex::new(1, 1);
+    for hex in center.spiral(2) {
+        assert_eq!(hex, hex.rotate_around(center, 6));
+        assert_eq!(
+            center.distance(hex),
+            center.distance(hex.rotate_around(center, 1))
+        );
+    }
+}
+
+#[test]
+fn layouts_round_trip_through_pixels() {
+    for &orientation in &[HexOrientation::Pointy, HexOrientation::Flat] {
+        let layout = HexLayout::new(
+            orientation,
+            Size::new(12.0f32, 9.0),
+            Point::new(-30.0, 40.0),
+        );
+        for hex in Hex::new(0, 0).spiral(4) {
+            let center = layout.to_point(hex);
+            assert_eq!(Some(hex), layout.to_hex(center).round().cast());
+            for &corner in layout.corners(hex).iter() {
+                let inside = Point::new(
+                    center.x() + (corner.x() - center.x()) * 0.9,
+                    center.y() + (corner.y() - center.y()) * 0.9,
+                );
+                assert_eq!(Some(hex), layout.to_hex(inside).round().cast());
+            }
+        }
+    }
+}
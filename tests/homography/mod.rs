@@ -0,0 +1,82 @@
+use crate::common::assert_close;
+use coord::prelude::*;
+
+fn quad(points: [(f64, f64); 4]) -> [Point<f64>; 4] {
+    [
+        Point::new(points[0].0, points[0].1),
+        Point::new(points[1].0, points[1].1),
+        Point::new(points[2].0, points[2].1),
+        Point::new(points[3].0, points[3].1),
+    ]
+}
+
+#[test]
+fn maps_quad_corners() {
+    let from = quad([(-3.0, 2.0), (10.0, -1.0), (12.0, 9.0), (-1.0, 7.0)]);
+    let to = quad([(0.0, 0.0), (64.0, 0.0), (64.0, 32.0), (0.0, 32.0)]);
+    let h = Homography::from_quads(from, to).unwrap();
+    for (&p, &q) in from.iter().zip(to.iter()) {
+        assert_close(q, h.transform_point(p).unwrap());
+    }
+}
+
+#[test]
+fn inverse_round_trips() {
+    let from = quad([(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    let to = quad([(2.0, 3.0), (8.0, 1.0), (9.0, 7.0), (1.0, 5.0)]);
+    let h = Homography::from_quads(from, to).unwrap();
+    let inverse = h.inverse().unwrap();
+    for &p in &[
+        Point::new(0.25, 0.75),
+        Point::new(0.5, 0.5),
+        Point::new(-2.0, 3.0),
+    ] {
+        assert_close(
+            p,
+            inverse
+                .transform_point(h.transform_point(p).unwrap())
+                .unwrap(),
+        );
+    }
+}
+
+#[test]
+fn keeps_affine_transforms() {
+    let t = Transform2D::rotation(Angle::new(0.3))
+        .then_scale(2.0, 0.5)
+        .then_translate(Vector::new(4.0, -1.0));
+    let from = quad([(0.0, 0.0), (3.0, 0.0), (3.0, 2.0), (0.0, 2.0)]);
+    let mut to = from;
+    for p in to.iter_mut() {
+        *p = t.transform_point(*p);
+    }
+    let h = Homography::from_quads(from, to).unwrap();
+    let p = Point::new(1.5, -7.0);
+    assert_close(t.transform_point(p), h.transform_point(p).unwrap());
+    assert_close(
+        t.transform_point(p),
+        Homography::from(t).transform_point(p).unwrap(),
+    );
+}
+
+#[test]
+fn rejects_degenerate_quads() {
+    let square = quad([(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    let line = quad([(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]);
+    assert_eq!(None, Homography::from_quads(square, line));
+    assert_eq!(None, Homography::from_quads(line, square));
+}
+
+#[test]
+fn rejects_quads_with_three_collinear_corners() {
+    let square = quad([(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    for &corners in &[
+        [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (1.0, 1.0)],
+        [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (20.0, 20.0)],
+        [(0.0, 0.0), (1e6, 0.0), (1e6, 1e6), (5e5, 5e5)],
+    ] {
+        let degenerate = quad(corners);
+        assert_eq!(None, Homography::from_quads(square, degenerate));
+        assert_eq!(None, Homography::from_quads(degenerate, square));
+    }
+}
@@ -1 +1,13 @@
-mod map;
\ No newline at end of file
+mod box_n;
+mod bsp;
+mod chunked_grid;
+mod common;
+mod grid;
+mod hex;
+mod homography;
+mod map;
+mod packing;
+mod symmetry;
+mod unit;
+mod viewport;
+mod wrapped_space;
@@ -0,0 +1,83 @@
+use coord::prelude::*;
+
+fn sizes() -> Vec<Size<i32>> {
+    (0..60)
+        .map(|i| Size::new(2 + (i * 7) % 13, 2 + (i * 5) % 11))
+        .collect()
+}
+
+fn assert_valid(container: Size<i32>, sizes: &[Size<i32>], result: &PackResult<i32>) {
+    let bounds = Rect::new(Point::new(0, 0), container);
+    let placements = result.placements();
+    assert_eq!(sizes.len(), placements.len() + result.failed().len());
+    for (i, &(index, placement)) in placements.iter().enumerate() {
+        let expected = if placement.rotated() {
+            sizes[index].transpose()
+        } else {
+            sizes[index]
+        };
+        assert_eq!(expected, placement.rect().size());
+        assert!(bounds.contains_rect(placement.rect()));
+        for &(_, other) in &placements[i + 1..] {
+            assert!(!placement.rect().intersects(other.rect()));
+        }
+    }
+}
+
+#[test]
+fn skyline_packs_without_overlap() {
+    let sizes = sizes();
+    let container = Size::new(64, 64);
+    let result = SkylinePacker::new(container).pack(&sizes);
+    assert_valid(container, &sizes, &result);
+    assert!(result.placements().len() > sizes.len() / 2);
+}
+
+#[test]
+fn max_rects_packs_without_overlap() {
+    let sizes = sizes();
+    let container = Size::new(64, 64);
+    let result = MaxRectsPacker::new(container)
+        .allow_rotation(true)
+        .pack(&sizes);
+    assert_valid(container, &sizes, &result);
+    assert!(result.placements().len() > sizes.len() / 2);
+}
+
+#[test]
+fn reports_failed_items() {
+    let sizes = vec![Size::new(4, 4), Size::new(20, 1), Size::new(4, 4)];
+    let result = MaxRectsPacker::new(Size::new(8, 4)).pack(&sizes);
+    assert_eq!(&[1], result.failed());
+    assert_eq!(
+        vec![0, 2],
+        result
+            .placements()
+            .iter()
+            .map(|&(index, _)| index)
+            .collect::<Vec<_>>()
+    );
+    assert!(!result.is_complete());
+}
+
+#[test]
+fn rotates_to_fit() {
+    let packer = SkylinePacker::new(Size::new(4, 10));
+    assert_eq!(None, packer.clone().insert(Size::new(10, 4)));
+    let mut packer = packer.allow_rotation(true);
+    let placement = packer.insert(Size::new(10, 4)).unwrap();
+    assert!(placement.rotated());
+    assert_eq!(
+        Rect::new(Point::new(0, 0), Size::new(4, 10)),
+        placement.rect()
+    );
+}
+
+#[test]
+fn fills_container_exactly() {
+    let sizes = vec![Size::new(5, 5); 4];
+    let result = SkylinePacker::new(Size::new(10, 10)).pack(&sizes);
+    assert!(result.is_complete());
+    let result = MaxRectsPacker::new(Size::new(10, 10)).pack(&sizes);
+    assert!(result.is_complete());
+}
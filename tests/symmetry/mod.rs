@@ -0,0 +1,50 @@
+use coord::prelude::*;
+
+#[test]
+fn composition_matches_application() {
+    let size = Size::new(5, 3);
+    for &a in Symmetry::ALL.iter() {
+        for &b in Symmetry::ALL.iter() {
+            let ab = a.then(b);
+            assert_eq!(
+                b.transform_vector(a.transform_vector(Vector::new(3, -7))),
+                ab.transform_vector(Vector::new(3, -7))
+            );
+            for cell in Rect::new(Point::new(0, 0), size).points() {
+                assert_eq!(
+                    b.transform_cell(a.transform_cell(cell, size), a.transform_size(size)),
+                    ab.transform_cell(cell, size)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn inverse_undoes_symmetry() {
+    let size = Size::new(4, 6);
+    let rect = Rect::new(Point::new(1, 2), Size::new(2, 3));
+    for &symmetry in Symmetry::ALL.iter() {
+        let inverse = symmetry.inverse();
+        assert_eq!(Symmetry::IDENTITY, symmetry.then(inverse));
+        assert_eq!(
+            rect,
+            inverse.transform_rect(
+                symmetry.transform_rect(rect, size),
+                symmetry.transform_size(size)
+            )
+        );
+    }
+}
+
+#[test]
+fn rect_contains_transformed_cells() {
+    let size = Size::new(7, 5);
+    let rect = Rect::new(Point::new(2, 1), Size::new(4, 3));
+    for &symmetry in Symmetry::ALL.iter() {
+        let transformed = symmetry.transform_rect(rect, size);
+        for cell in rect.points() {
+            assert!(transformed.contains(symmetry.transform_cell(cell, size)));
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use coord::prelude::*;
+
+enum Screen {}
+
+#[test]
+fn debug_names_the_type() {
+    assert_eq!("Point { x: 1, y: 2 }", format!("{:?}", Point::new(1, 2)));
+    assert_eq!(
+        "Point { x: 1, y: 2 }",
+        format!("{:?}", Point::<_, Screen>::typed(1, 2))
+    );
+    assert_eq!(
+        "PointN { components: [1, 2, 3] }",
+        format!("{:?}", PointN::new([1, 2, 3]))
+    );
+    assert_eq!(
+        "Rect { origin: Point { x: 0, y: 1 }, size: Size { width: 2, height: 3 } }",
+        format!("{:?}", Rect::new(Point::new(0, 1), Size::new(2, 3)))
+    );
+}
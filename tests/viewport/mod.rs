@@ -0,0 +1,68 @@
+use crate::common::assert_close;
+use coord::prelude::*;
+
+fn screen() -> Rect<f64> {
+    Rect::new(Point::new(0.0, 0.0), Size::new(320.0, 240.0))
+}
+
+#[test]
+fn round_trips_with_rotation() {
+    let mut viewport = Viewport::new(Point::new(12.0, -7.0), screen());
+    viewport.set_zoom(3.0);
+    viewport.set_rotation(Angle::new(0.7));
+    assert_close(
+        screen().center(),
+        viewport.world_to_screen(Point::new(12.0, -7.0)),
+    );
+    for &p in &[
+        Point::new(0.0, 0.0),
+        Point::new(-40.0, 13.5),
+        Point::new(100.0, 3.0),
+    ] {
+        assert_close(
+            p,
+            viewport
+                .screen_to_world(viewport.world_to_screen(p))
+                .unwrap(),
+        );
+    }
+}
+
+#[test]
+fn stays_inside_bounds() {
+    let bounds = Rect::new(Point::new(0.0, 0.0), Size::new(1000.0, 500.0));
+    let mut viewport = Viewport::new(Point::new(500.0, 250.0), screen());
+    viewport.set_bounds(Some(bounds));
+    for &delta in &[
+        Vector::new(-900.0, 0.0),
+        Vector::new(0.0, 900.0),
+        Vector::new(2000.0, -2000.0),
+    ] {
+        viewport.scroll_by(delta);
+        assert!(bounds.contains_rect(viewport.visible_world_rect().unwrap()));
+    }
+    viewport.set_zoom(0.25);
+    assert_close(bounds.center(), viewport.center());
+}
+
+#[test]
+fn deadzone_keeps_target_inside() {
+    let mut viewport = Viewport::new(Point::new(0.0, 0.0), screen());
+    viewport.set_rotation(Angle::new(0.3));
+    let deadzone = Size::new(64.0, 32.0);
+    let zone = Rect::from_center(screen().center(), deadzone);
+    for &target in &[
+        Point::new(10.0, 5.0),
+        Point::new(300.0, -20.0),
+        Point::new(-50.0, 80.0),
+    ] {
+        viewport.follow_with_deadzone(target, deadzone);
+        let p = viewport.world_to_screen(target);
+        assert!(
+            zone.inflate(Vector::new(1e-9, 1e-9)).contains(p),
+            "{} outside {:?}",
+            p,
+            zone
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use coord::prelude::*;
+use std::collections::HashSet;
+
+fn spaces() -> Vec<WrappedSpace<i32>> {
+    let size = Size::new(7, 5);
+    vec![
+        WrappedSpace::new(size, false, false),
+        WrappedSpace::horizontal(size),
+        WrappedSpace::vertical(size),
+        WrappedSpace::torus(size),
+    ]
+}
+
+#[test]
+fn split_rects_show_the_normalized_cells_once() {
+    let rects = [
+        Rect::new(Point::new(-3, -2), Size::new(4, 3)),
+        Rect::new(Point::new(5, 3), Size::new(6, 6)),
+        Rect::new(Point::new(-20, 12), Size::new(3, 1)),
+        Rect::new(Point::new(2, 1), Size::new(3, 2)),
+    ];
+    for space in spaces() {
+        for &rect in rects.iter() {
+            let expected = rect
+                .points()
+                .filter(|&p| space.contains(p))
+                .map(|p| space.normalize(p))
+                .collect::<HashSet<_>>();
+            let parts = space.split_rect(rect);
+            assert!(parts.len() <= 4);
+            let cells = parts.iter().flat_map(|r| r.points()).collect::<Vec<_>>();
+            assert_eq!(expected.len(), cells.len());
+            assert_eq!(expected, cells.into_iter().collect::<HashSet<_>>());
+            assert!(parts.iter().all(|&r| space.bounds().contains_rect(r)));
+        }
+    }
+}
+
+#[test]
+fn delta_is_shortest_and_lands_on_target() {
+    let space = WrappedSpace::torus(Size::new(7i32, 5));
+    for from in space.bounds().points() {
+        for to in space.bounds().points() {
+            let d = space.delta(from, to);
+            assert_eq!(to, space.normalize(from + d));
+            assert!(d.x().abs() * 2 <= 7 && d.y().abs() * 2 <= 5);
+        }
+    }
+}
+
+#[test]
+fn neighbors_are_one_step_away() {
+    for space in spaces() {
+        for p in space.bounds().points() {
+            for n in space.neighbors8(p) {
+                assert!(space.bounds().contains(n));
+                assert_eq!(1, space.chebyshev_distance(p, n));
+            }
+            assert!(space
+                .neighbors4(p)
+                .all(|n| space.manhattan_distance(p, n) == 1));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn empty_wrapping_axis() {
+    WrappedSpace::vertical(Size::new(10, 0));
+}
+
+#[test]
+fn neighbors_on_narrow_axes_are_distinct_from_the_point() {
+    let space = WrappedSpace::torus(Size::new(2, 1));
+    assert_eq!(
+        vec![Point::new(1, 0)],
+        space.neighbors4(Point::new(0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Point::new(1, 0)],
+        space.neighbors8(Point::new(0, 0)).collect::<Vec<_>>()
+    );
+    let space = WrappedSpace::torus(Size::new(1, 1));
+    assert_eq!(0, space.neighbors8(Point::new(0, 0)).count());
+    let space = WrappedSpace::horizontal(Size::new(2, 3));
+    assert_eq!(
+        vec![Point::new(0, 0), Point::new(1, 1), Point::new(0, 2)],
+        space.neighbors4(Point::new(0, 1)).collect::<Vec<_>>()
+    );
+}